@@ -1,4 +1,4 @@
-advent_of_code::solution!(2);
+advent_of_code::solution!(2025, 2);
 
 const POWERS_OF_10: [i64; 19] = [
     1,
@@ -33,7 +33,7 @@ pub fn part_one(input: &str) -> Option<u64> {
         })
         .filter(|&id| {
             let digit_count = (id.ilog10() + 1) as usize;
-            if digit_count % 2 != 0 {
+            if !digit_count.is_multiple_of(2) {
                 return false;
             }
             let divisor = POWERS_OF_10[digit_count / 2];
@@ -49,12 +49,16 @@ pub fn part_one(input: &str) -> Option<u64> {
 fn has_repeating_pattern(n: i64) -> bool {
     let digit_count = (n.ilog10() + 1) as usize;
 
-    for chunk_size in 1..=digit_count / 2 {
-        if digit_count % chunk_size != 0 {
+    for (chunk_size, &divisor) in POWERS_OF_10
+        .iter()
+        .enumerate()
+        .take(digit_count / 2 + 1)
+        .skip(1)
+    {
+        if !digit_count.is_multiple_of(chunk_size) {
             continue;
         }
 
-        let divisor = POWERS_OF_10[chunk_size];
         let first_chunk = n % divisor;
 
         // Quick check: build what the number should be if all chunks match
@@ -93,13 +97,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(1227775554));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(4174379265));
     }
 }
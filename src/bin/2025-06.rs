@@ -1,23 +1,34 @@
-advent_of_code::solution!(6);
+advent_of_code::solution!(2025, 6);
 
-pub fn part_one(input: &str) -> Option<u64> {
+use std::error::Error;
+
+use advent_of_code::template::InputError;
+
+pub fn part_one(input: &str) -> Result<u64, Box<dyn Error>> {
     let lines: Vec<&str> = input.lines().collect();
-    let operators: Vec<&str> = lines.last()?.split_whitespace().collect();
+    let operators: Vec<&str> = lines
+        .last()
+        .ok_or("input is empty")?
+        .split_whitespace()
+        .collect();
 
     // calculate sum/product for each column
     let mut sums = vec![0u64; operators.len()];
     let mut products = vec![1u64; operators.len()];
 
-    lines
+    for (line_number, line) in lines
         .iter()
-        .take_while(|line| !line.contains('+'))
-        .for_each(|line| {
-            line.split_whitespace().enumerate().for_each(|(i, num)| {
-                let n = num.parse::<u64>().unwrap();
-                sums[i] += n;
-                products[i] *= n;
-            });
-        });
+        .enumerate()
+        .take_while(|(_, line)| !line.contains('+'))
+    {
+        for (i, num) in line.split_whitespace().enumerate() {
+            let n = num
+                .parse::<u64>()
+                .map_err(|e| InputError::new(line_number + 1, line, e))?;
+            sums[i] += n;
+            products[i] *= n;
+        }
+    }
 
     // select sum or product based on operator
     let result = operators
@@ -30,7 +41,7 @@ pub fn part_one(input: &str) -> Option<u64> {
         })
         .sum::<u64>();
 
-    Some(result)
+    Ok(result)
 }
 
 struct Problem {
@@ -38,11 +49,11 @@ struct Problem {
     operator: char,
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
+pub fn part_two(input: &str) -> Result<u64, Box<dyn Error>> {
     let lines: Vec<&str> = input.lines().collect();
     
     if lines.is_empty() {
-        return None;
+        return Err("input is empty".into());
     }
     
     let width = lines[0].len();
@@ -61,14 +72,15 @@ pub fn part_two(input: &str) -> Option<u64> {
     let (problems, _, _) = transposed
         .iter()
         .chain(std::iter::once(&String::new()))
-        .fold(
+        .enumerate()
+        .try_fold(
             (Vec::new(), Vec::new(), '+'),
             |(mut problems, mut current_columns, mut cur_operator): (
                 Vec<Problem>,
                 Vec<u64>,
                 char,
             ),
-             line| {
+             (column, line)| {
                 if line.trim().is_empty() {
                     // end of current problem
                     if !current_columns.is_empty() {
@@ -86,11 +98,18 @@ pub fn part_two(input: &str) -> Option<u64> {
                         cur_operator = operator;
                     }
                     let str = chars.iter().collect::<String>();
-                    current_columns.push(str.trim().parse::<u64>().unwrap());
+                    let number = str.trim().parse::<u64>().map_err(|e| {
+                        format!(
+                            "invalid number {:?} in column {}: {e}",
+                            str.trim(),
+                            column + 1
+                        )
+                    })?;
+                    current_columns.push(number);
                 }
-                (problems, current_columns, cur_operator)
+                Ok::<_, String>((problems, current_columns, cur_operator))
             },
-        );
+        )?;
 
     let result = problems
         .into_iter()
@@ -101,7 +120,7 @@ pub fn part_two(input: &str) -> Option<u64> {
         })
         .sum();
 
-    Some(result)
+    Ok(result)
 }
 
 #[cfg(test)]
@@ -110,13 +129,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(4277556));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result.unwrap(), 4277556);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(3263827));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result.unwrap(), 3263827);
     }
 }
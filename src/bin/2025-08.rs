@@ -1,4 +1,4 @@
-advent_of_code::solution!(8);
+advent_of_code::solution!(2025, 8);
 
 #[derive(Debug, Clone, Copy)]
 struct Point {
@@ -68,19 +68,19 @@ impl UnionFind {
 pub fn part_one(input: &str) -> Option<u64> {
     let points: Vec<Point> = input
         .lines()
-        .filter_map(|line| {
+        .map(|line| {
             let parts: Vec<i32> = line.split(',').filter_map(|s| s.parse().ok()).collect();
-            Some(Point {
+            Point {
                 x: parts[0],
                 y: parts[1],
                 z: parts[2],
-            })
+            }
         })
         .collect();
 
     let n = points.len();
     let connections = if n == 20 { 10 } else { 1000 };
-    
+
     // Pre-allocate with exact capacity
     let mut distances = Vec::with_capacity(n * (n - 1) / 2);
 
@@ -123,7 +123,7 @@ pub fn part_two(input: &str) -> Option<u64> {
         .collect();
 
     let n = points.len();
-    
+
     // Pre-allocate with exact capacity
     let mut distances = Vec::with_capacity(n * (n - 1) / 2);
 
@@ -157,13 +157,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(40));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(25272));
     }
 }
@@ -1,6 +1,6 @@
 use std::collections::{HashMap, VecDeque};
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(2025, 9);
 
 fn parse_tiles(input: &str) -> Vec<(u64, u64)> {
     input
@@ -76,10 +76,8 @@ fn mark_polygon_edges(
         let min_y = y1.min(y2);
         let max_y = y1.max(y2);
 
-        for y in min_y..=max_y {
-            for x in min_x..=max_x {
-                grid[y][x] = true;
-            }
+        for row in &mut grid[min_y..=max_y] {
+            row[min_x..=max_x].fill(true);
         }
     }
 
@@ -87,7 +85,7 @@ fn mark_polygon_edges(
 }
 
 // Flood fill to mark outside cells
-fn flood_fill_outside(grid: &mut Vec<Vec<i32>>, inside_grid: &[Vec<bool>]) {
+fn flood_fill_outside(grid: &mut [Vec<i32>], inside_grid: &[Vec<bool>]) {
     let height = grid.len();
     let width = grid[0].len();
     let mut queue = VecDeque::new();
@@ -112,7 +110,7 @@ fn flood_fill_outside(grid: &mut Vec<Vec<i32>>, inside_grid: &[Vec<bool>]) {
 }
 
 // Build 2D prefix sum array
-fn build_prefix_sum(grid: &mut Vec<Vec<i32>>) {
+fn build_prefix_sum(grid: &mut [Vec<i32>]) {
     let height = grid.len();
     let width = grid[0].len();
 
@@ -143,7 +141,11 @@ fn query_rectangle_sum(grid: &[Vec<i32>], x1: usize, y1: usize, x2: usize, y2: u
 
     let left = if x1 > 0 { grid[y2][x1 - 1] } else { 0 };
     let top = if y1 > 0 { grid[y1 - 1][x2] } else { 0 };
-    let top_left = if x1 > 0 && y1 > 0 { grid[y1 - 1][x1 - 1] } else { 0 };
+    let top_left = if x1 > 0 && y1 > 0 {
+        grid[y1 - 1][x1 - 1]
+    } else {
+        0
+    };
 
     result - left - top + top_left
 }
@@ -179,11 +181,9 @@ pub fn part_two(input: &str) -> Option<u64> {
     flood_fill_outside(&mut grid, &inside_grid);
 
     // All remaining cells (value 2) are inside
-    for y in 0..height {
-        for x in 0..width {
-            if grid[y][x] == 2 {
-                grid[y][x] = 1;
-            }
+    for cell in grid.iter_mut().flatten() {
+        if *cell == 2 {
+            *cell = 1;
         }
     }
 
@@ -226,13 +226,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(50));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(24));
     }
 }
@@ -1,23 +1,26 @@
-advent_of_code::solution!(10);
+advent_of_code::solution!(2025, 10);
 
+use std::{error::Error, num::ParseIntError};
+
+use advent_of_code::template::{InputError, parse_lines};
 use good_lp::{
     default_solver, variable, variables, Expression, IntoAffineExpression, Solution, SolverModel,
     Variable,
 };
 
-fn parse_buttons(line: &str) -> Vec<Vec<usize>> {
+fn parse_buttons(line: &str) -> Result<Vec<Vec<usize>>, ParseIntError> {
     line.split_whitespace()
         .filter(|s| s.starts_with('('))
         .map(|s| {
             s.trim_matches(|c| c == '(' || c == ')')
                 .split(',')
-                .map(|n| n.parse().unwrap())
+                .map(|n| n.parse())
                 .collect()
         })
         .collect()
 }
 
-fn solve_with_ilp(targets: &[u64], buttons: &[Vec<usize>]) -> u64 {
+fn solve_with_ilp(targets: &[u64], buttons: &[Vec<usize>]) -> Result<u64, String> {
     let mut vars = variables!();
     let press_vars: Vec<Variable> = (0..buttons.len())
         .map(|_| vars.add(variable().integer().min(0)))
@@ -38,8 +41,11 @@ fn solve_with_ilp(targets: &[u64], buttons: &[Vec<usize>]) -> u64 {
     }
 
     match problem.solve() {
-        Ok(sol) => press_vars.iter().map(|&v| sol.value(v).round() as u64).sum(),
-        Err(_) => panic!("No solution found"),
+        Ok(sol) => Ok(press_vars
+            .iter()
+            .map(|&v| sol.value(v).round() as u64)
+            .sum()),
+        Err(e) => Err(format!("no solution found: {e}")),
     }
 }
 
@@ -109,46 +115,44 @@ fn solve_with_bitmask(target: &[bool], buttons: &[Vec<usize>]) -> usize {
         .unwrap()
 }
 
-pub fn part_one(input: &str) -> Option<u64> {
-    let total: usize = input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            let target: Vec<bool> = line
-                .split_whitespace()
-                .next()
-                .unwrap()
-                .trim_matches(|c| c == '[' || c == ']')
-                .chars()
-                .map(|c| c == '#')
-                .collect();
-            let buttons = parse_buttons(line);
-            solve_with_bitmask(&target, &buttons)
-        })
-        .sum();
-
-    Some(total as u64)
+pub fn part_one(input: &str) -> Result<u64, InputError> {
+    let presses = parse_lines(input, |line| -> Result<usize, Box<dyn Error>> {
+        if line.is_empty() {
+            return Ok(0);
+        }
+        let target: Vec<bool> = line
+            .split_whitespace()
+            .next()
+            .ok_or("missing indicator lights")?
+            .trim_matches(|c| c == '[' || c == ']')
+            .chars()
+            .map(|c| c == '#')
+            .collect();
+        let buttons = parse_buttons(line)?;
+        Ok(solve_with_bitmask(&target, &buttons))
+    })?;
+
+    Ok(presses.iter().sum::<usize>() as u64)
 }
 
-pub fn part_two(input: &str) -> Option<u64> {
-    let total: u64 = input
-        .lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| {
-            let targets: Vec<u64> = line
-                .split_whitespace()
-                .find(|s| s.starts_with('{'))
-                .unwrap()
-                .trim_matches(|c| c == '{' || c == '}')
-                .split(',')
-                .map(|n| n.parse().unwrap())
-                .collect();
-            let buttons = parse_buttons(line);
-            solve_with_ilp(&targets, &buttons)
-        })
-        .sum();
-
-    Some(total)
+pub fn part_two(input: &str) -> Result<u64, InputError> {
+    let presses = parse_lines(input, |line| -> Result<u64, Box<dyn Error>> {
+        if line.is_empty() {
+            return Ok(0);
+        }
+        let targets = line
+            .split_whitespace()
+            .find(|s| s.starts_with('{'))
+            .ok_or("missing joltage requirements")?
+            .trim_matches(|c| c == '{' || c == '}')
+            .split(',')
+            .map(|n| n.parse())
+            .collect::<Result<Vec<u64>, _>>()?;
+        let buttons = parse_buttons(line)?;
+        Ok(solve_with_ilp(&targets, &buttons)?)
+    })?;
+
+    Ok(presses.iter().sum())
 }
 
 #[cfg(test)]
@@ -157,13 +161,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(7));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result.unwrap(), 7);
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
-        assert_eq!(result, Some(33));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result.unwrap(), 33);
     }
 }
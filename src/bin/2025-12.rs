@@ -0,0 +1,76 @@
+advent_of_code::solution!(2025, 12);
+
+use std::error::Error;
+
+use advent_of_code::template::InputError;
+
+#[derive(Debug)]
+struct Region {
+    width: usize,
+    height: usize,
+    required: Vec<usize>,
+}
+
+fn parse_region(line: &str) -> Result<Region, Box<dyn Error>> {
+    let (dims, required) = line
+        .split_once(':')
+        .ok_or("missing `:` after the region size")?;
+    let (width, height) = dims
+        .split_once('x')
+        .ok_or("expected a region size like `4x4`")?;
+    let required = required
+        .split_whitespace()
+        .map(|s| s.parse())
+        .collect::<Result<_, _>>()?;
+    Ok(Region {
+        width: width.parse()?,
+        height: height.parse()?,
+        required,
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Region>, InputError> {
+    let mut regions = Vec::new();
+
+    for (i, line) in input.lines().enumerate() {
+        if line.contains('x') {
+            regions.push(parse_region(line).map_err(|e| InputError::new(i + 1, line, e))?);
+        }
+    }
+
+    Ok(regions)
+}
+
+pub fn part_one(input: &str) -> Result<u64, InputError> {
+    let regions = parse_input(input)?;
+
+    let count = regions.iter()
+        .filter(|region| {
+            let total_presents: usize = region.required.iter().sum();
+            total_presents * 8 < region.width * region.height
+        })
+        .count();
+
+    Ok(count as u64)
+}
+
+pub fn part_two(_input: &str) -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result.unwrap(), 2);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, None);
+    }
+}
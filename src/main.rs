@@ -1,81 +1,169 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, leaderboard, read, readme, scaffold, solve, time, today,
+};
+use advent_of_code::template::{Year, legacy_layout};
 use args::{AppArguments, parse};
 
-#[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
-
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::commands::scaffold::Overwrite;
+    use advent_of_code::template::{Day, Puzzle, Year};
     use std::process;
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: Puzzle,
+            force: bool,
         },
         Read {
-            day: Day,
+            puzzle: Puzzle,
+        },
+        Readme {
+            alloc: bool,
+        },
+        Leaderboard {
+            year: Year,
+            id: u64,
+            day: Option<Day>,
         },
         Scaffold {
-            day: Day,
+            puzzle: Puzzle,
             download: bool,
-            overwrite: bool,
+            overwrite: Overwrite,
+            dry_run: bool,
+            template: Option<String>,
+            sections: Vec<String>,
         },
         Solve {
-            day: Day,
+            puzzle: Puzzle,
             release: bool,
             dhat: bool,
+            alloc: bool,
             submit: Option<u8>,
+            input: Option<String>,
+            all_inputs: bool,
         },
         All {
+            year: Year,
             release: bool,
+            jobs: usize,
+            input: Option<String>,
+            verify: bool,
         },
         Time {
+            year: Year,
             all: bool,
             day: Option<Day>,
             store: bool,
+            jobs: usize,
+            fail_on_regression: Option<f64>,
+            alloc: bool,
+            input: Option<String>,
+        },
+        TimeHistory {
+            puzzle: Puzzle,
+        },
+        Today {
+            year: Year,
+            wait: bool,
         },
-        #[cfg(feature = "today")]
-        Today,
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+
+        // NOTE: every subcommand accepts `--year`, it defaults to the configured year.
+        let year = args
+            .opt_value_from_str("--year")?
+            .unwrap_or_else(Year::from_env);
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
+                year,
                 release: args.contains("--release"),
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
+                input: args.opt_value_from_str("--input")?,
+                verify: args.contains("--verify"),
+            },
+            Some("time") if args.contains("--history") => AppArguments::TimeHistory {
+                puzzle: Puzzle::checked(year, args.free_from_str()?)?,
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                // NOTE: timed runs stay serial by default to not pollute benchmarks.
+                let jobs = args.opt_value_from_str("--jobs")?.unwrap_or(1);
+                let fail_on_regression = args.opt_value_from_str("--fail-on-regression")?;
+                let alloc = args.contains("--alloc");
+                let input = args.opt_value_from_str("--input")?;
 
                 AppArguments::Time {
+                    year,
                     all,
-                    day: args.opt_free_from_str()?,
+                    day: args
+                        .opt_free_from_str()?
+                        .map(|day| Puzzle::checked(year, day))
+                        .transpose()?
+                        .map(|puzzle| puzzle.day),
                     store,
+                    jobs,
+                    fail_on_regression,
+                    alloc,
+                    input,
                 }
             }
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: Puzzle::checked(year, args.free_from_str()?)?,
+                force: args.contains("--force"),
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: Puzzle::checked(year, args.free_from_str()?)?,
+            },
+            Some("readme") => AppArguments::Readme {
+                alloc: args.contains("--alloc"),
+            },
+            Some("leaderboard") => AppArguments::Leaderboard {
+                year,
+                day: args
+                    .opt_value_from_str("--day")?
+                    .map(|day| Puzzle::checked(year, day))
+                    .transpose()?
+                    .map(|puzzle| puzzle.day),
+                id: args.free_from_str()?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
+                puzzle: Puzzle::checked(year, args.free_from_str()?)?,
                 download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
+                overwrite: Overwrite {
+                    module: args.contains("--overwrite"),
+                    input: args.contains("--overwrite-input"),
+                    example: args.contains("--overwrite-example"),
+                },
+                dry_run: args.contains("--dry-run"),
+                template: args.opt_value_from_str("--template")?,
+                // NOTE: sections can be passed one by one (`--with a --with b`) or comma-separated (`--with a,b`).
+                sections: args
+                    .values_from_str::<_, String>("--with")?
+                    .iter()
+                    .flat_map(|value| value.split(','))
+                    .map(|section| section.trim().to_string())
+                    .filter(|section| !section.is_empty())
+                    .collect(),
             },
             Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
+                puzzle: Puzzle::checked(year, args.free_from_str()?)?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                alloc: args.contains("--alloc"),
+                input: args.opt_value_from_str("--input")?,
+                all_inputs: args.contains("--all-inputs"),
+            },
+            Some("today") => AppArguments::Today {
+                year,
+                wait: args.contains("--wait"),
             },
-            #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -96,49 +184,76 @@ mod args {
 }
 
 fn main() {
+    legacy_layout::migrate(Year::from_env());
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
+            AppArguments::All {
+                year,
+                release,
+                jobs,
+                input,
+                verify,
+            } => all::handle(year, release, jobs, input.as_deref(), verify),
+            AppArguments::Time {
+                year,
+                day,
+                all,
+                store,
+                jobs,
+                fail_on_regression,
+                alloc,
+                input,
+            } => time::handle(
+                year,
                 day,
+                all,
+                store,
+                jobs,
+                fail_on_regression,
+                alloc,
+                input.as_deref(),
+            ),
+            AppArguments::TimeHistory { puzzle } => time::handle_history(puzzle),
+            AppArguments::Download { puzzle, force } => download::handle(puzzle, force),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
+            AppArguments::Readme { alloc } => readme::handle(alloc),
+            AppArguments::Leaderboard { year, id, day } => leaderboard::handle(year, id, day),
+            AppArguments::Scaffold {
+                puzzle,
                 download,
                 overwrite,
+                dry_run,
+                template,
+                sections,
             } => {
-                scaffold::handle(day, overwrite);
-                if download {
-                    download::handle(day);
+                scaffold::handle(puzzle, overwrite, dry_run, template.as_deref(), &sections);
+                if download && !dry_run {
+                    download::handle(puzzle, false);
                 }
             }
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
                 dhat,
+                alloc,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
-            #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
+                input,
+                all_inputs,
+            } => solve::handle(
+                puzzle,
+                release,
+                dhat,
+                alloc,
+                submit,
+                input.as_deref(),
+                all_inputs,
+            ),
+            AppArguments::Today { year, wait } => today::handle(year, wait),
         },
     };
 }
@@ -0,0 +1,156 @@
+//! Lightweight allocation tracking for solution runs, see the `alloc-stats` feature.
+//! The `solution!` macro installs [`CountingAlloc`] as global allocator when the feature is enabled.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// A global allocator that forwards to the system allocator and counts allocations.
+pub struct CountingAlloc;
+
+impl CountingAlloc {
+    fn track_alloc(size: usize) {
+        let size = size as u64;
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_LIVE_BYTES.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn track_dealloc(size: usize) {
+        LIVE_BYTES.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+}
+
+// SAFETY: all allocations are delegated to `System`, this only adds bookkeeping.
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            Self::track_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            Self::track_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        Self::track_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            Self::track_dealloc(layout.size());
+            Self::track_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Allocations made while running a solution part.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Total bytes allocated, including memory that was freed again.
+    pub bytes: u64,
+    /// Number of allocations, reallocations count as one.
+    pub allocations: u64,
+    /// Highest amount of live bytes above the amount live before the run.
+    pub peak_bytes: u64,
+}
+
+/// Returns `true` if allocations are counted, i.e. the `alloc-stats` feature is enabled.
+pub fn is_enabled() -> bool {
+    cfg!(all(feature = "alloc-stats", not(feature = "dhat-heap")))
+}
+
+/// Runs `func` and returns the allocations it made.
+/// Counts are only meaningful if [`CountingAlloc`] is the global allocator.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, AllocStats) {
+    let bytes_before = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let allocations_before = ALLOCATIONS.load(Ordering::Relaxed);
+    let live_before = LIVE_BYTES.load(Ordering::Relaxed);
+    PEAK_LIVE_BYTES.store(live_before, Ordering::Relaxed);
+
+    let result = func();
+
+    let stats = AllocStats {
+        bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes_before,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations_before,
+        peak_bytes: PEAK_LIVE_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(live_before),
+    };
+
+    (result, stats)
+}
+
+/// Formats a byte count with binary units, e.g. `1.5 KiB`.
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut value = bytes as f64;
+    let mut unit = "B";
+
+    for next in UNITS {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+
+    format!("{value:.1} {unit}")
+}
+
+impl AllocStats {
+    /// Formats the stats for display, e.g. `1.5 KiB in 3 allocs, peak 1.0 KiB`.
+    pub fn summary(&self) -> String {
+        format!(
+            "{} in {} allocs, peak {}",
+            format_bytes(self.bytes),
+            self.allocations,
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{AllocStats, format_bytes};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn formats_summary() {
+        let stats = AllocStats {
+            bytes: 2048,
+            allocations: 3,
+            peak_bytes: 100,
+        };
+        assert_eq!(stats.summary(), "2.0 KiB in 3 allocs, peak 100 B");
+    }
+}
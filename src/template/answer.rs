@@ -0,0 +1,164 @@
+//! Types that solution parts can return. Any [`Display`] type is an answer, e.g. numbers or strings.
+//! [`AsciiArt`] and [`WithDiagnostics`] separate the submitted answer from output that only helps to understand it.
+
+use std::fmt::Display;
+
+use crate::template::ocr::read_letters;
+
+/// The result of a solution part.
+pub trait Answer {
+    /// The answer that is submitted and compared to known answers.
+    fn to_answer(&self) -> String;
+
+    /// Output that is printed below the answer, but is not part of it.
+    fn diagnostics(&self) -> Option<String> {
+        None
+    }
+}
+
+impl<T: Display> Answer for T {
+    fn to_answer(&self) -> String {
+        self.to_string()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Letters drawn in pixels, e.g. `#` and `.`, that are read into the answer with the Advent of Code font.
+/// The drawing is kept as diagnostics. If the letters can not be read, the drawing itself is the answer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AsciiArt(pub String);
+
+impl AsciiArt {
+    /// Draw the lit pixels at `points`, given as `(x, y)`. The drawing is cropped to the lit pixels.
+    pub fn from_points(points: impl IntoIterator<Item = (i64, i64)>) -> Self {
+        let points: Vec<(i64, i64)> = points.into_iter().collect();
+
+        let Some(min_x) = points.iter().map(|(x, _)| *x).min() else {
+            return Self(String::new());
+        };
+        let max_x = points.iter().map(|(x, _)| *x).max().unwrap_or(min_x);
+        let min_y = points.iter().map(|(_, y)| *y).min().unwrap_or_default();
+        let max_y = points.iter().map(|(_, y)| *y).max().unwrap_or(min_y);
+
+        let rows: Vec<String> = (min_y..=max_y)
+            .map(|y| {
+                (min_x..=max_x)
+                    .map(|x| if points.contains(&(x, y)) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+
+        Self(rows.join("\n"))
+    }
+}
+
+impl From<String> for AsciiArt {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&str> for AsciiArt {
+    fn from(value: &str) -> Self {
+        Self(value.into())
+    }
+}
+
+impl Answer for AsciiArt {
+    fn to_answer(&self) -> String {
+        read_letters(&self.0).unwrap_or_else(|_| self.0.clone())
+    }
+
+    fn diagnostics(&self) -> Option<String> {
+        match read_letters(&self.0) {
+            Ok(_) => Some(self.0.clone()),
+            Err(e) => Some(format!("Could not read the letters: {e}")),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An answer together with output that explains it, e.g. an intermediate state or counters.
+/// Only `answer` is submitted and compared to known answers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WithDiagnostics<T> {
+    pub answer: T,
+    pub diagnostics: String,
+}
+
+impl<T> WithDiagnostics<T> {
+    pub fn new(answer: T, diagnostics: impl Display) -> Self {
+        Self {
+            answer,
+            diagnostics: diagnostics.to_string(),
+        }
+    }
+}
+
+impl<T: Answer> Answer for WithDiagnostics<T> {
+    fn to_answer(&self) -> String {
+        self.answer.to_answer()
+    }
+
+    fn diagnostics(&self) -> Option<String> {
+        match self.answer.diagnostics() {
+            Some(inner) => Some(format!("{inner}\n{}", self.diagnostics)),
+            None => Some(self.diagnostics.clone()),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{Answer, AsciiArt, WithDiagnostics};
+
+    fn get_mock_art() -> AsciiArt {
+        AsciiArt::from(["#..#", "#..#", "####", "#..#", "#..#", "#..#"].join("\n"))
+    }
+
+    #[test]
+    fn reads_ascii_art() {
+        let art = get_mock_art();
+        assert_eq!(art.to_answer(), "H");
+        assert_eq!(art.diagnostics(), Some(art.0.clone()));
+
+        let unreadable = AsciiArt::from("#\n#");
+        assert_eq!(unreadable.to_answer(), "#\n#");
+        assert!(
+            unreadable
+                .diagnostics()
+                .unwrap()
+                .starts_with("Could not read")
+        );
+    }
+
+    #[test]
+    fn draws_points() {
+        let art = AsciiArt::from_points([(10, 5), (12, 6)]);
+        assert_eq!(art.0, "#..\n..#");
+        assert_eq!(AsciiArt::from_points([]).0, "");
+    }
+
+    #[test]
+    fn keeps_diagnostics_out_of_answers() {
+        let answer = WithDiagnostics::new(42, "visited 7 nodes");
+        assert_eq!(answer.to_answer(), "42");
+        assert_eq!(answer.diagnostics().as_deref(), Some("visited 7 nodes"));
+
+        let answer = WithDiagnostics::new(get_mock_art(), "folded 2 times");
+        assert_eq!(answer.to_answer(), "H");
+        assert!(
+            answer
+                .diagnostics()
+                .unwrap()
+                .ends_with("#..#\nfolded 2 times")
+        );
+
+        assert_eq!("abc".to_answer(), "abc");
+        assert_eq!(7.diagnostics(), None);
+    }
+}
@@ -0,0 +1,122 @@
+//! Known answers for real puzzle inputs, stored in `data/{year}/answers/{day}.txt`.
+//! The first line holds the answer to part one, the second line the answer to part two.
+//! An empty line marks an answer that is not known yet.
+//! Answers for named inputs live in `data/{year}/answers/{day}/{name}.txt`.
+
+use std::{env, fs, io, path::PathBuf};
+
+use crate::template::Puzzle;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+impl Answers {
+    /// Read the known answers of `puzzle`. If the file is not present, no answers are known.
+    pub fn read(puzzle: Puzzle, input: Option<&str>) -> Self {
+        fs::read_to_string(get_answers_path(puzzle, input))
+            .map(|contents| Answers::from(contents.as_str()))
+            .unwrap_or_default()
+    }
+
+    /// Store `answer` as the known answer of `part`, keeping the answer of the other part.
+    pub fn store(
+        puzzle: Puzzle,
+        input: Option<&str>,
+        part: u8,
+        answer: &str,
+    ) -> Result<(), io::Error> {
+        let mut answers = Answers::read(puzzle, input);
+
+        match part {
+            1 => answers.part_1 = Some(answer.into()),
+            2 => answers.part_2 = Some(answer.into()),
+            _ => return Ok(()),
+        }
+
+        let path = get_answers_path(puzzle, input);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, answers.to_file_contents())
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_1.as_deref(),
+            2 => self.part_2.as_deref(),
+            _ => None,
+        }
+    }
+
+    fn to_file_contents(&self) -> String {
+        format!(
+            "{}\n{}\n",
+            self.part_1.as_deref().unwrap_or_default(),
+            self.part_2.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+impl From<&str> for Answers {
+    fn from(value: &str) -> Self {
+        let mut lines = value.lines().map(|line| {
+            let line = line.trim();
+            (!line.is_empty()).then(|| line.to_string())
+        });
+
+        Answers {
+            part_1: lines.next().flatten(),
+            part_2: lines.next().flatten(),
+        }
+    }
+}
+
+fn get_answers_path(puzzle: Puzzle, input: Option<&str>) -> PathBuf {
+    let path = env::current_dir()
+        .unwrap()
+        .join("data")
+        .join(puzzle.year.to_string())
+        .join("answers");
+
+    match input {
+        Some(name) => path
+            .join(puzzle.day.to_string())
+            .join(format!("{name}.txt")),
+        None => path.join(format!("{}.txt", puzzle.day)),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::Answers;
+
+    #[test]
+    fn parses_both_parts() {
+        let answers = Answers::from("42\n1337\n");
+        assert_eq!(answers.get(1), Some("42"));
+        assert_eq!(answers.get(2), Some("1337"));
+    }
+
+    #[test]
+    fn parses_missing_parts() {
+        assert_eq!(Answers::from("42").get(2), None);
+        assert_eq!(Answers::from("\n1337").get(1), None);
+        assert_eq!(Answers::from("\n1337").get(2), Some("1337"));
+        assert_eq!(Answers::from(""), Answers::default());
+    }
+
+    #[test]
+    fn roundtrips_file_contents() {
+        let answers = Answers {
+            part_1: None,
+            part_2: Some("1337".into()),
+        };
+        assert_eq!(answers.to_file_contents(), "\n1337\n");
+        assert_eq!(Answers::from(answers.to_file_contents().as_str()), answers);
+    }
+}
@@ -0,0 +1,299 @@
+//! Access to the Advent of Code website for downloading inputs, reading puzzles and submitting answers.
+//! Two backends implement [`AocBackend`]: the built-in HTTP client and the "aoc-cli" command-line.
+//! The backend is selected with the `AOC_BACKEND` environment variable (`native` or `aoc-cli`), defaulting to `native`.
+
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use crate::template::{
+    Puzzle, Year,
+    aoc_cli::{self, AocCommandError},
+    html,
+};
+
+static DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+/// Sent when `AOC_USER_AGENT` is not set. The maintainers of the website ask to include contact details there.
+static DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug)]
+pub enum AocError {
+    Cli(AocCommandError),
+    MissingSession,
+    Http(String),
+    IO(io::Error),
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Cli(e) => write!(f, "{e}"),
+            AocError::MissingSession => write!(
+                f,
+                "No session cookie found. Set `AOC_SESSION` or create the file \"~/.adventofcode.session\"."
+            ),
+            AocError::Http(e) => write!(f, "request failed: {e}"),
+            AocError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<AocCommandError> for AocError {
+    fn from(e: AocCommandError) -> Self {
+        AocError::Cli(e)
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        AocError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocError {
+    fn from(e: ureq::Error) -> Self {
+        AocError::Http(e.to_string())
+    }
+}
+
+pub trait AocBackend {
+    /// Check that the backend is usable, e.g. that it is installed or a session is configured.
+    fn check(&self) -> Result<(), AocError>;
+    /// Fetch the puzzle input of `puzzle`.
+    fn fetch_input(&self, puzzle: Puzzle) -> Result<String, AocError>;
+    /// Fetch the puzzle description of `puzzle` as markdown.
+    fn fetch_puzzle(&self, puzzle: Puzzle) -> Result<String, AocError>;
+    /// Submit `answer` for `part` of `puzzle`, returns the response message without printing it.
+    fn submit(&self, puzzle: Puzzle, part: u8, answer: &str) -> Result<String, AocError>;
+    /// Fetch the JSON of the private leaderboard `id` of `year`.
+    fn fetch_leaderboard(&self, year: Year, id: u64) -> Result<String, AocError>;
+}
+
+/// Returns the backend selected by `AOC_BACKEND`.
+pub fn backend() -> Box<dyn AocBackend> {
+    match env::var("AOC_BACKEND").as_deref() {
+        Ok("aoc-cli") => Box::new(AocCli),
+        _ => Box::new(NativeClient::from_env()),
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Backend that shells out to the "aoc-cli" command-line.
+pub struct AocCli;
+
+impl AocBackend for AocCli {
+    fn check(&self) -> Result<(), AocError> {
+        Ok(aoc_cli::check()?)
+    }
+
+    fn fetch_input(&self, puzzle: Puzzle) -> Result<String, AocError> {
+        let path = get_temp_path(&format!("input-{puzzle}.txt"));
+        aoc_cli::download_input(puzzle, &path)?;
+        read_temp_file(&path)
+    }
+
+    fn fetch_puzzle(&self, puzzle: Puzzle) -> Result<String, AocError> {
+        let path = get_temp_path(&format!("puzzle-{puzzle}.md"));
+        aoc_cli::download_puzzle(puzzle, &path)?;
+        read_temp_file(&path)
+    }
+
+    fn submit(&self, puzzle: Puzzle, part: u8, answer: &str) -> Result<String, AocError> {
+        let output = aoc_cli::submit(puzzle, part, answer)?;
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    fn fetch_leaderboard(&self, year: Year, id: u64) -> Result<String, AocError> {
+        // NOTE: aoc-cli only prints leaderboards as text, the built-in client shares its session file.
+        NativeClient::from_env().fetch_leaderboard(year, id)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Built-in HTTP client for the Advent of Code website.
+pub struct NativeClient {
+    base_url: String,
+    session: Option<String>,
+    user_agent: String,
+    agent: ureq::Agent,
+}
+
+impl NativeClient {
+    pub fn new(base_url: &str, session: Option<String>) -> Self {
+        let agent = ureq::Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Self {
+            base_url: base_url.trim_end_matches('/').into(),
+            session,
+            user_agent: DEFAULT_USER_AGENT.into(),
+            agent,
+        }
+    }
+
+    /// Configures the client from `AOC_BASE_URL`, `AOC_USER_AGENT` and the session cookie, see [`get_session`].
+    pub fn from_env() -> Self {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let client = Self::new(&base_url, get_session());
+
+        match env::var("AOC_USER_AGENT") {
+            Ok(user_agent) if !user_agent.trim().is_empty() => Self {
+                user_agent: user_agent.trim().into(),
+                ..client
+            },
+            _ => client,
+        }
+    }
+
+    fn get_day_url(&self, puzzle: Puzzle) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn get_cookie(&self) -> Result<String, AocError> {
+        self.session
+            .as_ref()
+            .map(|session| format!("session={session}"))
+            .ok_or(AocError::MissingSession)
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocError> {
+        let mut response = self
+            .agent
+            .get(url)
+            .header("Cookie", &self.get_cookie()?)
+            .header("User-Agent", &self.user_agent)
+            .call()?;
+
+        let status = response.status();
+        let body = response.body_mut().read_to_string()?;
+
+        if status.is_success() {
+            Ok(body)
+        } else {
+            Err(AocError::Http(format!(
+                "{url} responded with status {status}: {}",
+                body.trim()
+            )))
+        }
+    }
+
+    fn post(&self, url: &str, form: &[(&str, &str)]) -> Result<String, AocError> {
+        let mut response = self
+            .agent
+            .post(url)
+            .header("Cookie", &self.get_cookie()?)
+            .header("User-Agent", &self.user_agent)
+            .send_form(form.iter().copied())?;
+
+        let status = response.status();
+        let body = response.body_mut().read_to_string()?;
+
+        if status.is_success() {
+            Ok(body)
+        } else {
+            Err(AocError::Http(format!(
+                "{url} responded with status {status}: {}",
+                body.trim()
+            )))
+        }
+    }
+}
+
+impl AocBackend for NativeClient {
+    fn check(&self) -> Result<(), AocError> {
+        self.get_cookie().map(|_| ())
+    }
+
+    fn fetch_input(&self, puzzle: Puzzle) -> Result<String, AocError> {
+        self.get(&format!("{}/input", self.get_day_url(puzzle)))
+    }
+
+    fn fetch_puzzle(&self, puzzle: Puzzle) -> Result<String, AocError> {
+        let page = self.get(&self.get_day_url(puzzle))?;
+        Ok(html::puzzle_to_markdown(&page))
+    }
+
+    fn submit(&self, puzzle: Puzzle, part: u8, answer: &str) -> Result<String, AocError> {
+        let url = format!("{}/answer", self.get_day_url(puzzle));
+        let page = self.post(&url, &[("level", &part.to_string()), ("answer", answer)])?;
+
+        let message = html::extract_articles(&page)
+            .into_iter()
+            .map(html::to_markdown)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Ok(message)
+    }
+
+    fn fetch_leaderboard(&self, year: Year, id: u64) -> Result<String, AocError> {
+        self.get(&format!(
+            "{}/{year}/leaderboard/private/view/{id}.json",
+            self.base_url
+        ))
+    }
+}
+
+fn get_temp_path(name: &str) -> String {
+    env::temp_dir()
+        .join(format!("aoc-{}-{name}", std::process::id()))
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn read_temp_file(path: &str) -> Result<String, AocError> {
+    let contents = fs::read_to_string(path);
+    let _ = fs::remove_file(path);
+    Ok(contents?)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Reads the session cookie from the first source that is set:
+///  1. the `AOC_SESSION` or `ADVENT_OF_CODE_SESSION` environment variables.
+///  2. the file at `AOC_SESSION_FILE`.
+///  3. the files `~/.adventofcode.session` or `~/.config/adventofcode.session`, which are shared with aoc-cli.
+fn get_session() -> Option<String> {
+    let from_env = ["AOC_SESSION", "ADVENT_OF_CODE_SESSION"]
+        .into_iter()
+        .find_map(|key| env::var(key).ok());
+
+    if let Some(session) = from_env.filter(|s| !s.trim().is_empty()) {
+        return Some(session.trim().into());
+    }
+
+    let home = env::var("HOME").or_else(|_| env::var("USERPROFILE")).ok();
+
+    let mut paths: Vec<PathBuf> = vec![];
+    if let Ok(path) = env::var("AOC_SESSION_FILE") {
+        paths.push(path.into());
+    }
+    if let Some(home) = home {
+        paths.push(Path::new(&home).join(".adventofcode.session"));
+        paths.push(
+            Path::new(&home)
+                .join(".config")
+                .join("adventofcode.session"),
+        );
+    }
+
+    paths
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .map(|session| session.trim().to_string())
+        .find(|session| !session.is_empty())
+}
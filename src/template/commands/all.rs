@@ -1,5 +1,42 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::{ANSI_BOLD, ANSI_RESET, Year, run_multi::run_multi};
+
+pub fn handle(year: Year, is_release: bool, jobs: usize, input: Option<&str>, verify: bool) {
+    let summary = run_multi(
+        year,
+        &year.days().collect(),
+        is_release,
+        false,
+        jobs,
+        false,
+        input,
+    );
+
+    if !summary.failures.is_empty() || !summary.unsolved.is_empty() {
+        println!();
+        summary.print_failures();
+    }
+
+    if !verify {
+        return;
+    }
+
+    println!();
+
+    if summary.mismatches.is_empty() && summary.failures.is_empty() {
+        println!("{ANSI_BOLD}All known answers match.{ANSI_RESET}");
+    } else {
+        summary.print_mismatches();
+        if !summary.mismatches.is_empty() {
+            eprintln!(
+                "{} part(s) did not match their known answer.",
+                summary.mismatches.len()
+            );
+        }
+        if !summary.failures.is_empty() {
+            eprintln!("{} failure(s), see above.", summary.failures.len());
+        }
+        process::exit(1);
+    }
 }
@@ -1,16 +1,102 @@
-use crate::template::{Day, aoc_cli};
-use std::process;
-
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
+use std::{fs, path::Path, process};
+
+use crate::template::{
+    Puzzle,
+    aoc_cli::{get_input_path, get_puzzle_path},
+    aoc_client,
+    commands::scaffold::prefill_from_puzzle,
+    input_cache::{self, CacheStatus, InputManifest},
+    unlock::{Clock, SystemClock, retry_until_unlocked},
+};
+
+pub fn handle(puzzle: Puzzle, force: bool) {
+    handle_with_clock(puzzle, force, &SystemClock);
+}
+
+/// Download the input and puzzle of `puzzle`, reading the time and waiting between retries with `clock`.
+pub fn handle_with_clock(puzzle: Puzzle, force: bool, clock: &dyn Clock) {
+    let backend = aoc_client::backend();
+
+    if let Err(e) = backend.check() {
+        eprintln!("{e}");
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    let input_path = get_input_path(puzzle);
+
+    match input_cache::get_cache_status(puzzle, &input_path) {
+        CacheStatus::Valid(manifest) if !force => {
+            println!(
+                "🎄 Input for day {} was downloaded on {} UTC, skipping. Pass `--force` to download it again.",
+                puzzle.day,
+                manifest.formatted_date()
+            );
+        }
+        CacheStatus::Unverified if !force => {
+            println!(
+                "🎄 Input for day {} already exists at \"{input_path}\", skipping. Pass `--force` to download it again.",
+                puzzle.day
+            );
+        }
+        CacheStatus::Modified(manifest) if !force => {
+            println!(
+                "🎄 Input for day {} does not match the checksum recorded on {} UTC, keeping your changes. Pass `--force` to download it again.",
+                puzzle.day,
+                manifest.formatted_date()
+            );
+        }
+        _ => download_input(puzzle, &input_path, backend.as_ref(), clock),
+    }
+
+    let puzzle_path = get_puzzle_path(puzzle);
+
+    match backend.fetch_puzzle(puzzle).and_then(|markdown| {
+        write_file(&puzzle_path, &markdown)?;
+        Ok(markdown)
+    }) {
+        Ok(markdown) => {
+            println!("🎄 Successfully wrote puzzle to \"{puzzle_path}\".");
+            prefill_from_puzzle(puzzle, &markdown);
+        }
+        Err(e) => {
+            eprintln!("failed to download puzzle for day {}: {e}", puzzle.day);
+            process::exit(1);
+        }
+    }
+}
+
+fn download_input(
+    puzzle: Puzzle,
+    input_path: &str,
+    backend: &dyn aoc_client::AocBackend,
+    clock: &dyn Clock,
+) {
+    let input = match retry_until_unlocked(clock, puzzle, || backend.fetch_input(puzzle)) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("failed to download input for day {}: {e}", puzzle.day);
+            process::exit(1);
+        }
     };
+
+    if let Err(e) = input_cache::validate_input(&input) {
+        eprintln!("refusing to save input for day {}: {e}", puzzle.day);
+        process::exit(1);
+    }
+
+    let manifest = InputManifest::new(&input, clock.now());
+
+    if let Err(e) = write_file(input_path, &input).and_then(|()| manifest.store(puzzle)) {
+        eprintln!("failed to write input for day {}: {e}", puzzle.day);
+        process::exit(1);
+    }
+
+    println!("🎄 Successfully wrote input to \"{input_path}\".");
+}
+
+pub(crate) fn write_file(path: &str, contents: &str) -> Result<(), std::io::Error> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)
 }
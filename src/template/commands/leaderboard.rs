@@ -0,0 +1,72 @@
+use std::process;
+
+use crate::template::{
+    Day, Year, aoc_client,
+    commands::download::write_file,
+    leaderboard::{self, CACHE_DURATION, Leaderboard},
+};
+
+pub fn handle(year: Year, id: u64, day: Option<Day>) {
+    let json = match get_leaderboard_json(year, id) {
+        Ok(json) => json,
+        Err(e) => {
+            eprintln!("failed to fetch leaderboard {id}: {e}");
+            process::exit(1);
+        }
+    };
+
+    match Leaderboard::try_from(json) {
+        Ok(board) => println!("{}", leaderboard::render(&board, year, day)),
+        Err(e) => {
+            eprintln!("failed to parse leaderboard {id}: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+/// Read the leaderboard from the cache if it is recent enough, fetch and cache it otherwise.
+/// If fetching fails, an outdated cache is used as a fallback.
+fn get_leaderboard_json(year: Year, id: u64) -> Result<String, String> {
+    let cached = leaderboard::read_cache(year, id);
+
+    if let Some((json, age)) = &cached
+        && *age < CACHE_DURATION
+    {
+        println!(
+            "🎄 Using leaderboard fetched {} minutes ago. Leaderboards are refreshed every {} minutes.\n",
+            age.as_secs() / 60,
+            CACHE_DURATION.as_secs() / 60
+        );
+        return Ok(json.clone());
+    }
+
+    let fetched = aoc_client::backend()
+        .fetch_leaderboard(year, id)
+        .map_err(|e| e.to_string())
+        .and_then(|json| {
+            // NOTE: without access to the leaderboard, the website redirects to an HTML page.
+            Leaderboard::try_from(json.clone()).map_err(|_| {
+                format!(
+                    "the response is not a leaderboard. Is your session cookie valid and are you a member of leaderboard {id}?"
+                )
+            })?;
+            Ok(json)
+        });
+
+    match (fetched, cached) {
+        (Ok(json), _) => {
+            if let Err(e) = write_file(&leaderboard::get_cache_path(year, id), &json) {
+                eprintln!("Failed to cache leaderboard: {e}");
+            }
+            Ok(json)
+        }
+        (Err(e), Some((json, age))) => {
+            eprintln!(
+                "{e}\nUsing leaderboard fetched {} minutes ago instead.\n",
+                age.as_secs() / 60
+            );
+            Ok(json)
+        }
+        (Err(e), None) => Err(e),
+    }
+}
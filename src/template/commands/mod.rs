@@ -1,6 +1,9 @@
 pub mod all;
 pub mod download;
+pub mod leaderboard;
 pub mod read;
+pub mod readme;
 pub mod scaffold;
 pub mod solve;
 pub mod time;
+pub mod today;
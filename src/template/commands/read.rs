@@ -1,17 +1,28 @@
 use std::process;
 
-use crate::template::{Day, aoc_cli};
+use crate::template::{
+    Puzzle, aoc_cli::get_puzzle_path, aoc_client, commands::download::write_file,
+};
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
+pub fn handle(puzzle: Puzzle) {
+    let backend = aoc_client::backend();
+
+    if let Err(e) = backend.check() {
+        eprintln!("{e}");
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    let markdown = match backend.fetch_puzzle(puzzle) {
+        Ok(markdown) => markdown,
+        Err(e) => {
+            eprintln!("failed to read day {}: {e}", puzzle.day);
+            process::exit(1);
+        }
     };
+
+    if let Err(e) = write_file(&get_puzzle_path(puzzle), &markdown) {
+        eprintln!("failed to write puzzle for day {}: {e}", puzzle.day);
+    }
+
+    println!("{markdown}");
 }
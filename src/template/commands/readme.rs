@@ -0,0 +1,39 @@
+use std::{fs, process};
+
+use crate::template::readme_benchmarks::{self, Error};
+use crate::template::readme_stars;
+use crate::template::timings::Timings;
+
+/// Refresh the readme tables from the stored timings and local data.
+pub fn handle(show_alloc: bool) {
+    let timings = Timings::read_from_file().unwrap_or_else(|e| {
+        eprintln!("{e} Keeping the benchmark table as is.");
+        Timings::default()
+    });
+
+    match update(timings, show_alloc) {
+        Ok(()) => println!("Updated the readme."),
+        Err(_) => {
+            eprintln!("Failed to update the readme.");
+            process::exit(1);
+        }
+    }
+}
+
+/// Updates the stars and the benchmark tables of the readme in one pass.
+/// The stars table is optional and only updated if the readme contains its marker.
+/// Without stored timings, the benchmark table is kept as is.
+pub(crate) fn update(timings: Timings, show_alloc: bool) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+
+    if readme.contains(readme_stars::MARKER) {
+        readme_stars::update_content(&mut readme, &readme_stars::collect(&timings))?;
+    }
+    if !timings.data.is_empty() {
+        readme_benchmarks::update_content(&mut readme, timings, show_alloc)?;
+    }
+
+    fs::write(path, &readme)?;
+    Ok(())
+}
@@ -1,79 +1,241 @@
-use std::{
-    fs::{File, OpenOptions},
-    io::Write,
-    process,
+use std::{fs, process};
+
+use crate::template::{
+    Puzzle, Year,
+    aoc_cli::get_puzzle_path,
+    commands::download::write_file,
+    examples::{extract_example, extract_part_one_answer, prefill_answer},
+    templates::{self, Variables},
 };
 
-use crate::template::Day;
+/// The files created by `scaffold`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Artifact {
+    Module,
+    Input,
+    Example,
+}
 
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+impl Artifact {
+    fn name(self) -> &'static str {
+        match self {
+            Artifact::Module => "module",
+            Artifact::Input => "input",
+            Artifact::Example => "example",
+        }
+    }
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
-    let mut file = OpenOptions::new();
-    if overwrite {
-        file.create(true);
-    } else {
-        file.create_new(true);
+    /// The flag that allows replacing an existing file of this kind.
+    fn overwrite_flag(self) -> &'static str {
+        match self {
+            Artifact::Module => "--overwrite",
+            Artifact::Input => "--overwrite-input",
+            Artifact::Example => "--overwrite-example",
+        }
     }
-    file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// The kinds of existing files that `scaffold` may replace. Existing files are kept by default.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Overwrite {
+    pub module: bool,
+    pub input: bool,
+    pub example: bool,
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
-
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
-        Err(e) => {
-            eprintln!("Failed to create module file: {e}");
-            process::exit(1);
+impl Overwrite {
+    fn allows(self, artifact: Artifact) -> bool {
+        match artifact {
+            Artifact::Module => self.module,
+            Artifact::Input => self.input,
+            Artifact::Example => self.example,
         }
-    };
+    }
+}
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Action {
+    Create,
+    Replace,
+    Keep,
+}
+
+struct Step {
+    artifact: Artifact,
+    path: String,
+    contents: String,
+    action: Action,
+}
+
+impl Step {
+    fn new(artifact: Artifact, path: String, contents: String, overwrite: Overwrite) -> Self {
+        // NOTE: if it can not be checked whether the file exists, creating it fails and is rolled back.
+        let action = match fs::exists(&path) {
+            Ok(true) if overwrite.allows(artifact) => Action::Replace,
+            Ok(true) => Action::Keep,
+            _ => Action::Create,
+        };
+        Self {
+            artifact,
+            path,
+            contents,
+            action,
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+    }
+
+    fn describe(&self, dry_run: bool) -> String {
+        let Step { artifact, path, .. } = self;
+        let name = artifact.name();
+        let (create, replace) = if dry_run {
+            ("Would create", "Would replace")
+        } else {
+            ("Created", "Replaced")
+        };
+
+        match (self.action, artifact) {
+            (Action::Create, Artifact::Module) => format!("{create} {name} file \"{path}\""),
+            (Action::Create, _) => format!("{create} empty {name} file \"{path}\""),
+            (Action::Replace, Artifact::Module) => format!("{replace} {name} file \"{path}\""),
+            (Action::Replace, _) => {
+                format!("{replace} {name} file \"{path}\" with an empty one")
+            }
+            (Action::Keep, _) => format!(
+                "Kept existing {name} file \"{path}\". Pass `{}` to replace it.",
+                artifact.overwrite_flag()
+            ),
         }
     }
+}
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+/// A file written by a scaffold and its previous contents, to undo the write if a later step fails.
+struct Written {
+    path: String,
+    previous: Option<Vec<u8>>,
+}
+
+fn rollback(written: &[Written]) {
+    for Written { path, previous } in written.iter().rev() {
+        let result = match previous {
+            Some(previous) => fs::write(path, previous),
+            None => fs::remove_file(path),
+        };
+        match result {
+            Ok(()) => eprintln!("Rolled back \"{path}\"."),
+            Err(e) => eprintln!("Failed to roll back \"{path}\": {e}"),
         }
+    }
+}
+
+/// Scaffold a day from the template `template` (see [`templates`]), keeping its optional `sections`.
+///
+/// Only missing files are created, existing ones are replaced if `overwrite` allows it for their kind.
+/// If writing any file fails, the files written before are restored. With `dry_run`, the planned steps are only printed.
+pub fn handle(
+    puzzle: Puzzle,
+    overwrite: Overwrite,
+    dry_run: bool,
+    template: Option<&str>,
+    sections: &[String],
+) {
+    // NOTE: rendered before creating any file, so an invalid template does not leave an empty module behind.
+    let module = match templates::load(template)
+        .and_then(|template| templates::render(&template, &Variables::new(puzzle), sections))
+    {
+        Ok(module) => module,
         Err(e) => {
-            eprintln!("Failed to create input file: {e}");
+            eprintln!("Failed to render module template: {e}");
             process::exit(1);
         }
-    }
+    };
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    let steps = [
+        Step::new(Artifact::Module, puzzle.bin_path(), module, overwrite),
+        Step::new(
+            Artifact::Input,
+            puzzle.data_path("inputs", "txt"),
+            String::new(),
+            overwrite,
+        ),
+        Step::new(
+            Artifact::Example,
+            puzzle.data_path("examples", "txt"),
+            String::new(),
+            overwrite,
+        ),
+    ];
+
+    if dry_run {
+        for step in &steps {
+            println!("{}", step.describe(true));
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+        return;
+    }
+
+    let mut written: Vec<Written> = vec![];
+
+    for step in &steps {
+        if step.action != Action::Keep {
+            let previous = fs::read(&step.path).ok();
+            if let Err(e) = write_file(&step.path, &step.contents) {
+                eprintln!("Failed to write {} file: {e}", step.artifact.name());
+                rollback(&written);
+                process::exit(1);
+            }
+            written.push(Written {
+                path: step.path.clone(),
+                previous,
+            });
         }
+        println!("{}", step.describe(false));
+    }
+
+    // NOTE: the puzzle might have been downloaded before scaffolding the day.
+    if let Ok(markdown) = fs::read_to_string(get_puzzle_path(puzzle)) {
+        prefill_from_puzzle(puzzle, &markdown);
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    // NOTE: the year only needs to be passed if it differs from the configured one.
+    let year_arg = if puzzle.year == Year::from_env() {
+        String::new()
+    } else {
+        format!(" --year {}", puzzle.year)
+    };
+    println!(
+        "🎄 Type `cargo solve {}{year_arg}` to run your solution.",
+        puzzle.day
+    );
+}
+
+/// Fill the example file and the expected answer of part one from the puzzle description.
+/// Only fills what is still empty, so examples and tests edited by hand are kept.
+pub(crate) fn prefill_from_puzzle(puzzle: Puzzle, markdown: &str) {
+    let example_path = puzzle.data_path("examples", "txt");
+    let has_example = fs::read_to_string(&example_path).is_ok_and(|s| !s.trim().is_empty());
+
+    if !has_example && let Some(example) = extract_example(markdown) {
+        match write_file(&example_path, &example) {
+            Ok(()) => println!("🎄 Extracted example to \"{example_path}\"."),
+            Err(e) => eprintln!("Failed to write example file: {e}"),
+        }
+    }
+
+    let module_path = puzzle.bin_path();
+
+    let Some(answer) = extract_part_one_answer(markdown) else {
+        return;
+    };
+    let Some(module) = fs::read_to_string(&module_path)
+        .ok()
+        .and_then(|module| prefill_answer(&module, &answer))
+    else {
+        return;
+    };
+
+    match fs::write(&module_path, module) {
+        Ok(()) => println!(
+            "🎄 Pre-filled the expected answer of part one ({answer}) in \"{module_path}\"."
+        ),
+        Err(e) => eprintln!("Failed to write module file: {e}"),
+    }
 }
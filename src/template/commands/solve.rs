@@ -1,9 +1,17 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::Puzzle;
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+pub fn handle(
+    puzzle: Puzzle,
+    release: bool,
+    dhat: bool,
+    alloc: bool,
+    submit_part: Option<u8>,
+    input: Option<&str>,
+    all_inputs: bool,
+) {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.to_string()];
 
     if dhat {
         cmd_args.extend([
@@ -12,12 +20,24 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
             "--features".to_string(),
             "dhat-heap".to_string(),
         ]);
-    } else if release {
-        cmd_args.push("--release".to_string());
+    } else {
+        if release {
+            cmd_args.push("--release".to_string());
+        }
+        if alloc {
+            cmd_args.extend(["--features".to_string(), "alloc-stats".to_string()]);
+        }
     }
 
     cmd_args.push("--".to_string());
 
+    if let Some(input) = input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input.to_string());
+    } else if all_inputs {
+        cmd_args.push("--all-inputs".to_string());
+    }
+
     if let Some(submit_part) = submit_part {
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
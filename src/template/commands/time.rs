@@ -1,34 +1,95 @@
 use std::collections::HashSet;
+use std::process;
+use std::time::Duration;
 
-use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::history::History;
+use crate::template::protocol::{PARSE_PART, part_label};
+use crate::template::run_multi::{child_commands, run_multi};
+use crate::template::timings::{TIMINGS_FILE_PATH, TimingDelta, Timings};
+use crate::template::{
+    ANSI_BOLD, ANSI_GREEN, ANSI_RED, ANSI_RESET, Day, Puzzle, Year, commands::readme,
+};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
-    let stored_timings = Timings::read_from_file();
+#[allow(clippy::too_many_arguments)]
+pub fn handle(
+    year: Year,
+    day: Option<Day>,
+    run_all: bool,
+    store: bool,
+    jobs: usize,
+    fail_on_regression: Option<f64>,
+    alloc: bool,
+    input: Option<&str>,
+) {
+    let stored_timings = match Timings::read_from_file() {
+        Ok(timings) => timings,
+        // NOTE: storing would overwrite the unreadable file with the new timings only.
+        Err(e) if store => {
+            eprintln!("{e}");
+            eprintln!("Not storing timings, fix or remove \"{TIMINGS_FILE_PATH}\" first.");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            Timings::default()
+        }
+    };
 
     let days_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                year.days().collect()
             } else {
-                // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                // when the `--all` flag is not set, filter out days that are fully benched with the selected input.
+                year.days()
+                    .filter(|day| !stored_timings.is_day_complete(Puzzle::new(year, *day), input))
                     .collect()
             }
         },
         |day| HashSet::from([day]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(year, &days_to_run, true, true, jobs, alloc, input)
+        .timings
+        .unwrap();
+
+    let deltas = stored_timings.compare(&timings);
+    print_deltas(&deltas);
+
+    let regressions: Vec<&TimingDelta> = fail_on_regression
+        .map(|threshold| {
+            deltas
+                .iter()
+                .filter(|delta| delta.change_percent() > threshold)
+                .collect()
+        })
+        .unwrap_or_default();
+
+    if !regressions.is_empty() {
+        eprintln!();
+        for delta in &regressions {
+            eprintln!(
+                "Day {} {} regressed by {:.1}%.",
+                delta.day,
+                part_label(delta.part),
+                delta.change_percent()
+            );
+        }
+        eprintln!("Benchmarks regressed beyond the threshold, not storing timings.");
+        process::exit(1);
+    }
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
+        let profile = child_commands::build_profile(true, alloc);
+        if let Err(e) = History::append(&timings, &profile) {
+            eprintln!("Failed to append benchmarks to history: {e}");
+        }
+
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme::update(merged_timings, alloc) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -38,3 +99,85 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
         }
     }
 }
+
+fn print_deltas(deltas: &[TimingDelta]) {
+    if deltas.is_empty() {
+        return;
+    }
+
+    println!("\n{ANSI_BOLD}Compared to stored timings:{ANSI_RESET}");
+
+    for delta in deltas {
+        let change = delta.change_percent();
+        let color = if change > 0.0 { ANSI_RED } else { ANSI_GREEN };
+        let label = if change > 0.0 { "slower" } else { "faster" };
+
+        println!(
+            "Day {} {}: {:.1?} → {:.1?} ({color}{change:+.1}% {label}{ANSI_RESET})",
+            delta.day,
+            part_label(delta.part),
+            Duration::from_nanos(delta.previous_nanos),
+            Duration::from_nanos(delta.current_nanos),
+        );
+    }
+}
+
+/// Print how the timings of `puzzle` changed over all stored benchmark runs.
+pub fn handle_history(puzzle: Puzzle) {
+    let day = puzzle.day;
+    let history = History::read_from_file();
+    let entries = history.for_puzzle_with_previous(puzzle);
+
+    if entries.is_empty() {
+        println!("No stored benchmarks for day {day}. Run `cargo time {day} --store` first.");
+        return;
+    }
+
+    println!("{ANSI_BOLD}Day {day} history{ANSI_RESET}");
+    println!("------");
+
+    for (entry, previous) in entries {
+        let parts: Vec<String> = [PARSE_PART, 1, 2]
+            .into_iter()
+            .filter_map(|part| {
+                let Some(nanos) = entry.timing.part_nanos(part) else {
+                    // NOTE: most solutions do not have a parse phase, skip it instead of printing `-`.
+                    return (part != PARSE_PART).then(|| format!("{}: -", part_label(part)));
+                };
+
+                let change = previous
+                    .and_then(|previous| previous.timing.part_nanos(part))
+                    .map(|previous_nanos| {
+                        TimingDelta {
+                            day,
+                            part,
+                            previous_nanos,
+                            current_nanos: nanos,
+                        }
+                        .change_percent()
+                    })
+                    .map(|change| {
+                        let color = if change > 0.0 { ANSI_RED } else { ANSI_GREEN };
+                        format!(" ({color}{change:+.1}%{ANSI_RESET})")
+                    })
+                    .unwrap_or_default();
+
+                Some(format!(
+                    "{}: {:.1?}{change}",
+                    part_label(part),
+                    Duration::from_nanos(nanos)
+                ))
+            })
+            .collect();
+
+        println!(
+            "{} {} {}/{} {} | {}",
+            entry.formatted_date(),
+            entry.commit.as_deref().unwrap_or("-------"),
+            entry.machine,
+            entry.profile,
+            entry.input_name(),
+            parts.join(" | ")
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::{path::Path, process};
+
+use crate::template::{
+    Puzzle, Year,
+    commands::{download, read, scaffold},
+    unlock::{Clock, SystemClock, current_puzzle, next_unlock, wait_for_unlock},
+};
+
+pub fn handle(year: Year, wait: bool) {
+    handle_with_clock(year, wait, &SystemClock);
+}
+
+/// Scaffold, download and read the puzzle of the current day, reading the time from `clock`.
+/// With `wait`, an already scaffolded current puzzle is skipped and the next puzzle is awaited instead.
+pub fn handle_with_clock(year: Year, wait: bool, clock: &dyn Clock) {
+    let puzzle = match get_puzzle(year, wait, clock) {
+        Ok(puzzle) => puzzle,
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    };
+
+    scaffold::handle(puzzle, scaffold::Overwrite::default(), false, None, &[]);
+    download::handle_with_clock(puzzle, false, clock);
+    read::handle(puzzle);
+}
+
+/// Resolve the puzzle to work on, blocking until it unlocks if `wait` is set.
+pub fn get_puzzle(year: Year, wait: bool, clock: &dyn Clock) -> Result<Puzzle, String> {
+    let now = clock.now();
+
+    if let Some(puzzle) = current_puzzle(year, now)
+        && (!wait || !Path::new(&puzzle.bin_path()).exists())
+    {
+        return Ok(puzzle);
+    }
+
+    if !wait {
+        return Err(format!(
+            "`today` command can only be run on the first {} days of december {year}. \
+            Please use `scaffold` with a specific day or wait for the next puzzle with `--wait`.",
+            year.event_days()
+        ));
+    }
+
+    let puzzle = next_unlock(year, now)
+        .ok_or_else(|| format!("all puzzles of Advent of Code {year} are already unlocked."))?;
+
+    wait_for_unlock(clock, puzzle);
+    Ok(puzzle)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::get_puzzle;
+    use crate::template::unlock::{Clock, MockClock, unlock_timestamp};
+    use crate::{puzzle, year};
+
+    #[test]
+    fn uses_the_puzzle_of_the_current_day() {
+        let clock = MockClock::new(unlock_timestamp(puzzle!(2025, 8)) - 3);
+        assert_eq!(get_puzzle(year!(2025), false, &clock), Ok(puzzle!(2025, 7)));
+        assert_eq!(clock.sleeps.get(), 0);
+    }
+
+    #[test]
+    fn waits_for_the_next_puzzle() {
+        // NOTE: day 7 is scaffolded in this repository, so `--wait` skips it.
+        let unlock = unlock_timestamp(puzzle!(2025, 8));
+        let clock = MockClock::new(unlock - 3);
+        assert_eq!(get_puzzle(year!(2025), true, &clock), Ok(puzzle!(2025, 8)));
+        assert_eq!(clock.now(), unlock);
+    }
+
+    #[test]
+    fn requires_an_unlocked_puzzle() {
+        let clock = MockClock::new(unlock_timestamp(puzzle!(2025, 12)) + 86_400);
+        assert!(get_puzzle(year!(2025), false, &clock).is_err());
+        assert!(get_puzzle(year!(2025), true, &clock).is_err());
+        assert_eq!(clock.sleeps.get(), 0);
+    }
+}
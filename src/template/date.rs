@@ -0,0 +1,117 @@
+//! Calendar helpers for unix timestamps, so the template does not need a date crate.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A UTC calendar date with time of day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DateTime {
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+impl DateTime {
+    /// Convert seconds since the unix epoch to a UTC date.
+    pub fn from_timestamp(timestamp: u64) -> Self {
+        let days = (timestamp / 86_400) as i64;
+        let seconds_of_day = (timestamp % 86_400) as u32;
+
+        // see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let doe = z.rem_euclid(146_097);
+        let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = yoe + era * 400 + i64::from(month <= 2);
+
+        Self {
+            year,
+            month,
+            day,
+            hour: seconds_of_day / 3600,
+            minute: seconds_of_day % 3600 / 60,
+            second: seconds_of_day % 60,
+        }
+    }
+
+    /// Convert the date to seconds since the unix epoch. Dates before the epoch are clamped to it.
+    pub fn to_timestamp(self) -> u64 {
+        // see: http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let year = self.year - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let yoe = year.rem_euclid(400);
+        let mp = i64::from((self.month + 9) % 12);
+        let doy = (153 * mp + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        let days = era * 146_097 + doe - 719_468;
+
+        let seconds = days * 86_400
+            + i64::from(self.hour) * 3600
+            + i64::from(self.minute) * 60
+            + i64::from(self.second);
+
+        u64::try_from(seconds).unwrap_or(0)
+    }
+}
+
+/// Seconds since the unix epoch.
+pub fn now_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::DateTime;
+
+    #[test]
+    fn converts_timestamps() {
+        assert_eq!(
+            DateTime::from_timestamp(0),
+            DateTime {
+                year: 1970,
+                month: 1,
+                day: 1,
+                hour: 0,
+                minute: 0,
+                second: 0
+            }
+        );
+        assert_eq!(
+            DateTime::from_timestamp(1_765_202_585),
+            DateTime {
+                year: 2025,
+                month: 12,
+                day: 8,
+                hour: 14,
+                minute: 3,
+                second: 5
+            }
+        );
+    }
+
+    #[test]
+    fn converts_leap_days() {
+        let date = DateTime::from_timestamp(1_709_164_800);
+        assert_eq!((date.year, date.month, date.day), (2024, 2, 29));
+    }
+
+    #[test]
+    fn roundtrips_timestamps() {
+        for timestamp in [0, 951_782_400, 1_709_164_800, 1_765_202_585, 4_102_444_799] {
+            assert_eq!(
+                DateTime::from_timestamp(timestamp).to_timestamp(),
+                timestamp
+            );
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! Extraction of examples and their expected answers from puzzle descriptions.
+//! Works on the markdown written to `data/{year}/puzzles/{day}.md`, where examples are fenced code blocks.
+
+use std::fmt::Write;
+
+/// Placeholder assertion of the generated tests, see `src/template.txt`.
+static ANSWER_PLACEHOLDER: &str = "assert_eq!(result, None);";
+
+struct CodeBlock {
+    /// The paragraph that introduces the block.
+    context: String,
+    content: String,
+}
+
+/// Pick the most likely example input of part one.
+/// Prefers the first block introduced by a paragraph mentioning an example, falling back to the longest block.
+pub fn extract_example(markdown: &str) -> Option<String> {
+    let blocks = code_blocks(part_one(markdown));
+
+    blocks
+        .iter()
+        .filter(|block| block.content.lines().count() > 1)
+        .find(|block| block.context.to_ascii_lowercase().contains("example"))
+        .or_else(|| {
+            blocks
+                .iter()
+                .max_by_key(|block| block.content.lines().count())
+        })
+        .map(|block| block.content.clone())
+}
+
+/// The expected answer of part one for the example, i.e. the last emphasized code span of part one.
+pub fn extract_part_one_answer(markdown: &str) -> Option<String> {
+    emphasized_code(part_one(markdown))
+        .last()
+        .map(|value| value.trim().to_string())
+}
+
+/// Replace the placeholder assertion of `test_part_one` in a generated module with `answer`.
+/// Returns `None` if the answer is not a number or the test was already edited.
+pub fn prefill_answer(module: &str, answer: &str) -> Option<String> {
+    let answer: u64 = answer.parse().ok()?;
+
+    let test_start = module.find("fn test_part_one()")?;
+    let test_end = module[test_start..]
+        .find("fn test_part_two()")
+        .map_or(module.len(), |end| test_start + end);
+
+    let placeholder = test_start + module[test_start..test_end].find(ANSWER_PLACEHOLDER)?;
+
+    let mut module = module.to_string();
+    module.replace_range(
+        placeholder..placeholder + ANSWER_PLACEHOLDER.len(),
+        &format!("assert_eq!(result, Some({answer}));"),
+    );
+    Some(module)
+}
+
+/// The description of part one, i.e. everything before the heading of part two.
+fn part_one(markdown: &str) -> &str {
+    markdown
+        .find("--- Part Two ---")
+        .map_or(markdown, |end| &markdown[..end])
+}
+
+fn code_blocks(markdown: &str) -> Vec<CodeBlock> {
+    let mut blocks = vec![];
+    let mut context = String::new();
+    let mut content: Option<String> = None;
+
+    for line in markdown.lines() {
+        let is_fence = line.trim_start().starts_with("```");
+
+        match content.as_mut() {
+            Some(block) if is_fence => {
+                blocks.push(CodeBlock {
+                    context: last_paragraph(&context).to_string(),
+                    content: block.clone(),
+                });
+                context.clear();
+                content = None;
+            }
+            Some(block) => {
+                // NOTE: writing to a string can not fail.
+                let _ = writeln!(block, "{line}");
+            }
+            None if is_fence => content = Some(String::new()),
+            None => {
+                let _ = writeln!(context, "{line}");
+            }
+        }
+    }
+
+    blocks
+}
+
+fn last_paragraph(text: &str) -> &str {
+    let text = text.trim();
+    text.rfind("\n\n").map_or(text, |start| &text[start + 2..])
+}
+
+/// Contents of code spans that are emphasized, e.g. `` `*11*` `` or `` *`11`* ``, in order of appearance.
+fn emphasized_code(markdown: &str) -> Vec<&str> {
+    let mut values = vec![];
+    let mut in_block = false;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_block = !in_block;
+            continue;
+        }
+        if in_block {
+            continue;
+        }
+
+        // NOTE: every odd part of a line split at backticks is the content of a code span.
+        let parts: Vec<&str> = line.split('`').collect();
+        for (i, span) in parts.iter().enumerate().skip(1).step_by(2) {
+            if i + 1 == parts.len() {
+                break;
+            }
+
+            let inner = span.strip_prefix('*').and_then(|s| s.strip_suffix('*'));
+            let is_wrapped = parts[i - 1].ends_with('*') && parts[i + 1].starts_with('*');
+
+            match inner {
+                Some(inner) if !inner.is_empty() => values.push(inner),
+                _ if is_wrapped && !span.is_empty() => values.push(span),
+                _ => {}
+            }
+        }
+    }
+
+    values
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{extract_example, extract_part_one_answer, prefill_answer};
+
+    const PUZZLE: &str = "## --- Day 1: Test ---
+
+The list looks like this:
+
+```
+a
+```
+
+For example:
+
+```
+3   4
+4   3
+```
+
+In the example above, the distances add up to `*11*`.
+
+What is the total distance?
+
+## --- Part Two ---
+
+Another example:
+
+```
+1
+2
+3
+```
+
+The answer is `*31*`.
+";
+
+    #[test]
+    fn extracts_examples() {
+        assert_eq!(extract_example(PUZZLE), Some("3   4\n4   3\n".into()));
+        assert_eq!(
+            extract_example("Some text.\n\n```\n1\n```\n\n```\n1\n2\n```\n"),
+            Some("1\n2\n".into())
+        );
+        assert_eq!(extract_example("No blocks."), None);
+    }
+
+    #[test]
+    fn extracts_part_one_answers() {
+        assert_eq!(extract_part_one_answer(PUZZLE), Some("11".into()));
+        assert_eq!(
+            extract_part_one_answer("The answer is *`abc`* or `*12*`, not `3`."),
+            Some("12".into())
+        );
+        assert_eq!(extract_part_one_answer("Nothing `5` *here*."), None);
+    }
+
+    #[test]
+    fn prefills_answers() {
+        let module = "fn test_part_one() {\n    assert_eq!(result, None);\n}\n\nfn test_part_two() {\n    assert_eq!(result, None);\n}\n";
+
+        let filled = prefill_answer(module, "11").unwrap();
+        assert_eq!(
+            filled,
+            "fn test_part_one() {\n    assert_eq!(result, Some(11));\n}\n\nfn test_part_two() {\n    assert_eq!(result, None);\n}\n"
+        );
+        assert_eq!(prefill_answer(&filled, "11"), None);
+        assert_eq!(prefill_answer(module, "abc"), None);
+    }
+}
@@ -0,0 +1,365 @@
+//! Append-only log of every stored benchmark run.
+//! Unlike `timings.json`, which only holds the latest timing per day, entries are never overwritten.
+
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, OpenOptions},
+    io::{Error, Write},
+    process::Command,
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::Puzzle;
+use crate::template::date::{DateTime, now_timestamp};
+use crate::template::timings::{Timing, Timings};
+
+static HISTORY_FILE_PATH: &str = "./data/timings-history.jsonl";
+
+/// A timing of a single day, together with the context it was measured in.
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub commit: Option<String>,
+    pub machine: String,
+    pub profile: String,
+    pub timing: Timing,
+}
+
+/// Represents the benchmark history for a set of days.
+/// Stored as JSON lines, one entry per line.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    pub entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Append an entry for each timing to the history file.
+    pub fn append(timings: &Timings, profile: &str) -> Result<(), Error> {
+        let timestamp = now_timestamp();
+        let commit = get_commit_hash();
+        let machine = get_machine_name();
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(HISTORY_FILE_PATH)?;
+
+        for timing in &timings.data {
+            let entry = HistoryEntry {
+                timestamp,
+                commit: commit.clone(),
+                machine: machine.clone(),
+                profile: profile.into(),
+                timing: timing.clone(),
+            };
+            // NOTE: stringifying a JSON value built from strings and numbers can not fail.
+            writeln!(file, "{}", JsonValue::from(&entry).stringify().unwrap())?;
+        }
+
+        Ok(())
+    }
+
+    /// Rehydrate history from the history file. If not present, returns an empty history.
+    /// Lines that can not be parsed, e.g. after an interrupted write, are skipped with a warning.
+    pub fn read_from_file() -> Self {
+        let Ok(contents) = fs::read_to_string(HISTORY_FILE_PATH) else {
+            return History::default();
+        };
+
+        let (history, errors) = History::parse(&contents);
+        for (line, error) in errors {
+            eprintln!("Warning: skipped line {line} of \"{HISTORY_FILE_PATH}\": {error}");
+        }
+        history
+    }
+
+    /// All entries for `puzzle`, oldest first.
+    pub fn for_puzzle(&self, puzzle: Puzzle) -> Vec<&HistoryEntry> {
+        let mut entries: Vec<&HistoryEntry> = self
+            .entries
+            .iter()
+            .filter(|entry| entry.timing.puzzle() == puzzle)
+            .collect();
+        entries.sort_by_key(|entry| entry.timestamp);
+        entries
+    }
+
+    /// All entries for `puzzle`, oldest first, each with the latest earlier entry it can be compared to.
+    /// Runtimes are only comparable when they were measured with the same input, profile and machine.
+    pub fn for_puzzle_with_previous(
+        &self,
+        puzzle: Puzzle,
+    ) -> Vec<(&HistoryEntry, Option<&HistoryEntry>)> {
+        let entries = self.for_puzzle(puzzle);
+
+        entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| {
+                let previous = entries[..i]
+                    .iter()
+                    .rev()
+                    .find(|previous| previous.is_comparable_to(entry));
+                (*entry, previous.copied())
+            })
+            .collect()
+    }
+}
+
+impl HistoryEntry {
+    /// Whether the runtimes of both entries were measured under the same conditions.
+    pub fn is_comparable_to(&self, other: &HistoryEntry) -> bool {
+        self.timing.input == other.timing.input
+            && self.profile == other.profile
+            && self.machine == other.machine
+    }
+
+    /// Name of the input the entry was measured with, `default` for the default input.
+    pub fn input_name(&self) -> &str {
+        self.timing.input.as_deref().unwrap_or("default")
+    }
+
+    /// Formats the timestamp as an UTC date time, e.g. `2025-12-08 14:03`.
+    pub fn formatted_date(&self) -> String {
+        let date = DateTime::from_timestamp(self.timestamp);
+        format!(
+            "{:04}-{:02}-{:02} {:02}:{:02}",
+            date.year, date.month, date.day, date.hour, date.minute
+        )
+    }
+}
+
+fn get_commit_hash() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let hash = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    (!hash.is_empty()).then_some(hash)
+}
+
+fn get_machine_name() -> String {
+    env::var("AOC_MACHINE")
+        .or_else(|_| env::var("HOSTNAME"))
+        .or_else(|_| env::var("COMPUTERNAME"))
+        .ok()
+        .or_else(|| {
+            let output = Command::new("hostname").output().ok()?;
+            Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        })
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "unknown".into())
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl History {
+    /// Parse the lines of a history file, returning the line numbers and errors of the lines that were skipped.
+    fn parse(contents: &str) -> (Self, Vec<(usize, String)>) {
+        let mut history = History::default();
+        let mut errors = vec![];
+
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let entry = JsonValue::from_str(line)
+                .or(Err("not valid JSON line.".to_string()))
+                .and_then(|json| HistoryEntry::try_from(&json));
+
+            match entry {
+                Ok(entry) => history.entries.push(entry),
+                Err(e) => errors.push((i + 1, e)),
+            }
+        }
+
+        (history, errors)
+    }
+}
+
+impl From<&HistoryEntry> for JsonValue {
+    fn from(value: &HistoryEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert(
+            "commit".into(),
+            value
+                .commit
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert("machine".into(), JsonValue::String(value.machine.clone()));
+        map.insert("profile".into(), JsonValue::String(value.profile.clone()));
+        map.insert("timing".into(), JsonValue::from(&value.timing));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for HistoryEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected history entry to be a JSON object.")?;
+
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u64)
+            .ok_or("Expected entry.timestamp to be a number.")?;
+
+        let commit = json
+            .get("commit")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected entry.commit to be null or string.")?;
+
+        let machine = json
+            .get("machine")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected entry.machine to be a string.")?;
+
+        let profile = json
+            .get("profile")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected entry.profile to be a string.")?;
+
+        let timing = json
+            .get("timing")
+            .ok_or("Expected entry to have key `timing`.")
+            .map(Timing::try_from)??;
+
+        Ok(HistoryEntry {
+            timestamp,
+            commit: commit.cloned(),
+            machine: machine.clone(),
+            profile: profile.clone(),
+            timing,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{History, HistoryEntry};
+    use crate::{
+        day, puzzle,
+        template::{
+            Day,
+            timings::{
+                Timing,
+                fixtures::{part, timing},
+            },
+        },
+    };
+    use tinyjson::JsonValue;
+
+    fn get_mock_entry(day: u8, timestamp: u64) -> HistoryEntry {
+        HistoryEntry {
+            timestamp,
+            commit: Some("abc1234".into()),
+            machine: "box".into(),
+            profile: "release".into(),
+            timing: timing(Day::new(day).unwrap(), part(10), None),
+        }
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let lines = [get_mock_entry(1, 10), get_mock_entry(2, 20)]
+            .iter()
+            .map(|entry| JsonValue::from(entry).stringify().unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let (history, errors) = History::parse(&lines);
+        assert!(errors.is_empty());
+        assert_eq!(history.entries.len(), 2);
+        assert_eq!(history.entries[0].commit, Some("abc1234".into()));
+        assert_eq!(history.entries[1].timing.day, day!(2));
+        assert_eq!(history.entries[1].timestamp, 20);
+    }
+
+    #[test]
+    fn filters_and_sorts_by_day() {
+        let history = History {
+            entries: vec![
+                get_mock_entry(1, 30),
+                get_mock_entry(2, 20),
+                get_mock_entry(1, 10),
+            ],
+        };
+
+        let entries = history.for_puzzle(puzzle!(2025, 1));
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].timestamp, 10);
+        assert_eq!(entries[1].timestamp, 30);
+    }
+
+    #[test]
+    fn compares_entries_of_the_same_input_and_profile() {
+        let named = HistoryEntry {
+            timing: Timing {
+                input: Some("large".into()),
+                ..get_mock_entry(1, 20).timing
+            },
+            ..get_mock_entry(1, 20)
+        };
+        let alloc = HistoryEntry {
+            profile: "release+alloc-stats".into(),
+            ..get_mock_entry(1, 30)
+        };
+        let history = History {
+            entries: vec![get_mock_entry(1, 10), named, alloc, get_mock_entry(1, 40)],
+        };
+
+        let previous: Vec<Option<u64>> = history
+            .for_puzzle_with_previous(puzzle!(2025, 1))
+            .into_iter()
+            .map(|(_, previous)| previous.map(|entry| entry.timestamp))
+            .collect();
+        assert_eq!(previous, [None, None, None, Some(10)]);
+    }
+
+    #[test]
+    fn skips_malformed_lines() {
+        let lines = [
+            JsonValue::from(&get_mock_entry(1, 10)).stringify().unwrap(),
+            r#"{ "timestamp": 1 }"#.into(),
+            "{ \"timest".into(),
+            JsonValue::from(&get_mock_entry(2, 20)).stringify().unwrap(),
+        ]
+        .join("\n");
+
+        let (history, errors) = History::parse(&lines);
+        assert_eq!(history.entries.len(), 2);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].0, 2);
+        assert_eq!(errors[1], (3, "not valid JSON line.".into()));
+    }
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(get_mock_entry(1, 0).formatted_date(), "1970-01-01 00:00");
+        assert_eq!(
+            get_mock_entry(1, 1_765_202_580).formatted_date(),
+            "2025-12-08 14:03"
+        );
+    }
+}
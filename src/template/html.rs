@@ -0,0 +1,261 @@
+//! Minimal conversion of Advent of Code pages to markdown.
+//! Only covers the handful of tags used in puzzle descriptions and submission responses.
+
+enum Token<'a> {
+    Open { name: String, attrs: &'a str },
+    Close(String),
+    Text(&'a str),
+}
+
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("<!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+
+        if !rest.starts_with('<') {
+            let end = rest.find('<').unwrap_or(rest.len());
+            tokens.push(Token::Text(&rest[..end]));
+            rest = &rest[end..];
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+
+        let tag = rest[1..end].trim_end_matches('/');
+        rest = &rest[end + 1..];
+
+        let (is_close, tag) = match tag.strip_prefix('/') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+
+        let name_end = tag
+            .find(|c: char| !c.is_ascii_alphanumeric())
+            .unwrap_or(tag.len());
+        let name = tag[..name_end].to_ascii_lowercase();
+
+        if is_close {
+            tokens.push(Token::Close(name));
+        } else {
+            tokens.push(Token::Open {
+                name,
+                attrs: &tag[name_end..],
+            });
+        }
+    }
+
+    tokens
+}
+
+fn get_attribute<'a>(attrs: &'a str, key: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{key}=\""))? + key.len() + 2;
+    let len = attrs[start..].find('"')?;
+    Some(&attrs[start..start + len])
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let Some(end) = rest.find(';').filter(|end| *end <= 10) else {
+            result.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+
+        let decoded = match &rest[1..end] {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "amp" => Some('&'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            entity => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+
+        match decoded {
+            Some(c) => {
+                result.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    result.push_str(rest);
+    result
+}
+
+/// Convert a fragment of HTML to markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut in_pre = false;
+    let mut links: Vec<Option<String>> = vec![];
+
+    for token in tokenize(html) {
+        match token {
+            Token::Open { name, attrs } => match name.as_str() {
+                "h1" | "h2" | "h3" => out.push_str("## "),
+                "pre" => {
+                    out.push_str("```\n");
+                    in_pre = true;
+                }
+                "code" if !in_pre => out.push('`'),
+                "em" if !in_pre => out.push('*'),
+                "li" => out.push_str("- "),
+                "br" => out.push('\n'),
+                "a" => {
+                    let href = get_attribute(attrs, "href").map(decode_entities);
+                    if href.is_some() {
+                        out.push('[');
+                    }
+                    links.push(href);
+                }
+                _ => {}
+            },
+            Token::Close(name) => match name.as_str() {
+                "h1" | "h2" | "h3" | "p" => out.push_str("\n\n"),
+                "pre" => {
+                    if !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                    out.push_str("```\n\n");
+                    in_pre = false;
+                }
+                "code" if !in_pre => out.push('`'),
+                "em" if !in_pre => out.push('*'),
+                "li" => out.push('\n'),
+                "ul" => out.push('\n'),
+                "a" => {
+                    if let Some(Some(href)) = links.pop() {
+                        out.push_str(&format!("]({href})"));
+                    }
+                }
+                _ => {}
+            },
+            Token::Text(text) => {
+                let text = decode_entities(text);
+
+                if in_pre {
+                    out.push_str(&text);
+                    continue;
+                }
+
+                let is_line_start = out.is_empty() || out.ends_with('\n');
+                let mut collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+                if collapsed.is_empty() {
+                    if !is_line_start && !text.is_empty() && !out.ends_with(' ') {
+                        out.push(' ');
+                    }
+                    continue;
+                }
+
+                if text.starts_with(char::is_whitespace) && !is_line_start && !out.ends_with(' ') {
+                    collapsed.insert(0, ' ');
+                }
+                if text.ends_with(char::is_whitespace) {
+                    collapsed.push(' ');
+                }
+
+                out.push_str(&collapsed);
+            }
+        }
+    }
+
+    let lines: Vec<&str> = out.lines().map(str::trim_end).collect();
+    format!("{}\n", lines.join("\n").trim())
+}
+
+/// Contents of all `<article>` elements of a page, which hold the puzzle description and submission responses.
+pub fn extract_articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(content_start) = rest[start..].find('>').map(|i| start + i + 1) else {
+            break;
+        };
+        let Some(end) = rest[content_start..].find("</article>") else {
+            break;
+        };
+        articles.push(&rest[content_start..content_start + end]);
+        rest = &rest[content_start + end..];
+    }
+
+    articles
+}
+
+/// Convert the puzzle description of an Advent of Code day page to markdown.
+pub fn puzzle_to_markdown(html: &str) -> String {
+    extract_articles(html)
+        .into_iter()
+        .map(to_markdown)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{decode_entities, puzzle_to_markdown, to_markdown};
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode_entities("a &lt; b &amp;&amp; c &gt; d"),
+            "a < b && c > d"
+        );
+        assert_eq!(decode_entities("it&#39;s &#x41;"), "it's A");
+        assert_eq!(decode_entities("AT&T"), "AT&T");
+    }
+
+    #[test]
+    fn converts_inline_elements() {
+        assert_eq!(
+            to_markdown(
+                "<p>The <em>first</em> value is <code>42</code>, see <a href=\"/2025/day/1\">here</a>.</p>"
+            ),
+            "The *first* value is `42`, see [here](/2025/day/1).\n"
+        );
+    }
+
+    #[test]
+    fn converts_block_elements() {
+        let html = "<h2>--- Day 1: Test ---</h2><p>Some\n text.</p>\n<pre><code>1 2\n3 &lt; 4\n</code></pre>\n<ul>\n<li>one</li>\n<li>two</li>\n</ul>";
+        assert_eq!(
+            to_markdown(html),
+            "## --- Day 1: Test ---\n\nSome text.\n\n```\n1 2\n3 < 4\n```\n\n- one\n- two\n"
+        );
+    }
+
+    #[test]
+    fn converts_only_articles() {
+        let html = "<html><body><header>nav</header><main><article class=\"day-desc\"><h2>--- Day 1 ---</h2><p>One</p></article><p>Your puzzle answer was <code>1</code>.</p><article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Two</p></article></main></body></html>";
+        assert_eq!(
+            puzzle_to_markdown(html),
+            "## --- Day 1 ---\n\nOne\n\n## --- Part Two ---\n\nTwo\n"
+        );
+    }
+}
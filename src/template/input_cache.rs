@@ -0,0 +1,214 @@
+//! Bookkeeping for downloaded puzzle inputs.
+//! Next to each downloaded input, a manifest (`data/{year}/inputs/{day}.manifest.json`) records its checksum and when it was fetched.
+
+use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::Puzzle;
+use crate::template::date::DateTime;
+
+/// Metadata of a downloaded input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InputManifest {
+    /// Checksum of the input, see [`checksum`].
+    pub checksum: String,
+    /// Seconds since the unix epoch.
+    pub fetched_at: u64,
+    pub bytes: u64,
+}
+
+/// State of the cached input of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CacheStatus {
+    /// No input, or an empty placeholder created by `scaffold`.
+    Missing,
+    /// The input matches the checksum in its manifest.
+    Valid(InputManifest),
+    /// The input was not downloaded by the template, e.g. pasted by hand.
+    Unverified,
+    /// The input changed since it was downloaded, e.g. because it was edited by hand.
+    Modified(InputManifest),
+}
+
+impl InputManifest {
+    pub fn new(input: &str, fetched_at: u64) -> Self {
+        Self {
+            checksum: checksum(input),
+            fetched_at,
+            bytes: input.len() as u64,
+        }
+    }
+
+    /// Formats the fetch time as an UTC date time, e.g. `2025-12-08 05:00`.
+    pub fn formatted_date(&self) -> String {
+        let date = DateTime::from_timestamp(self.fetched_at);
+        format!(
+            "{:04}-{:02}-{:02} {:02}:{:02}",
+            date.year, date.month, date.day, date.hour, date.minute
+        )
+    }
+
+    pub fn read(puzzle: Puzzle) -> Option<Self> {
+        let contents = fs::read_to_string(get_manifest_path(puzzle)).ok()?;
+        InputManifest::try_from(contents).ok()
+    }
+
+    pub fn store(&self, puzzle: Puzzle) -> Result<(), Error> {
+        // NOTE: stringifying a JSON value built from strings and numbers can not fail.
+        let json = JsonValue::from(self).format().unwrap();
+        fs::write(get_manifest_path(puzzle), json)
+    }
+}
+
+/// Check the cached input at `input_path` against the manifest of `puzzle`.
+pub fn get_cache_status(puzzle: Puzzle, input_path: &str) -> CacheStatus {
+    let input = fs::read_to_string(input_path).unwrap_or_default();
+
+    if input.trim().is_empty() {
+        return CacheStatus::Missing;
+    }
+
+    match InputManifest::read(puzzle) {
+        Some(manifest) if manifest.checksum == checksum(&input) => CacheStatus::Valid(manifest),
+        Some(manifest) => CacheStatus::Modified(manifest),
+        None => CacheStatus::Unverified,
+    }
+}
+
+/// Checks that a downloaded input looks like a puzzle input.
+/// Rejects empty and truncated responses as well as HTML pages, e.g. a login page served instead of the input.
+pub fn validate_input(input: &str) -> Result<(), String> {
+    if input.trim().is_empty() {
+        return Err("the input is empty.".into());
+    }
+
+    let start = input.trim_start().to_ascii_lowercase();
+    if start.starts_with("<!doctype") || start.starts_with("<html") {
+        return Err(
+            "received an HTML page instead of the input. Is your session cookie valid?".into(),
+        );
+    }
+
+    for message in [
+        "Please log in",
+        "Puzzle inputs differ by user",
+        "Please don't repeatedly request this endpoint before it unlocks",
+    ] {
+        if input.contains(message) {
+            return Err(format!(
+                "received an error message instead of the input: \"{}\"",
+                input.trim()
+            ));
+        }
+    }
+
+    // NOTE: inputs are always terminated by a newline, a missing one hints at an interrupted download.
+    if !input.ends_with('\n') {
+        return Err("the input is not terminated by a newline and might be truncated.".into());
+    }
+
+    Ok(())
+}
+
+/// 64-bit FNV-1a hash of `input`, prefixed with the algorithm, e.g. `fnv1a64:af63bd4c8601b7df`.
+pub fn checksum(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("fnv1a64:{hash:016x}")
+}
+
+fn get_manifest_path(puzzle: Puzzle) -> String {
+    puzzle.data_path("inputs", "manifest.json")
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&InputManifest> for JsonValue {
+    fn from(value: &InputManifest) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("checksum".into(), JsonValue::String(value.checksum.clone()));
+        map.insert(
+            "fetched_at".into(),
+            JsonValue::Number(value.fetched_at as f64),
+        );
+        map.insert("bytes".into(), JsonValue::Number(value.bytes as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for InputManifest {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected manifest to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|v| *v as u64)
+                .ok_or(format!("Expected manifest.{key} to be a number."))
+        };
+
+        let checksum = json
+            .get("checksum")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected manifest.checksum to be a string.")?;
+
+        Ok(InputManifest {
+            checksum: checksum.clone(),
+            fetched_at: number("fetched_at")?,
+            bytes: number("bytes")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{InputManifest, checksum, validate_input};
+    use tinyjson::JsonValue;
+
+    #[test]
+    fn computes_checksums() {
+        assert_eq!(checksum(""), "fnv1a64:cbf29ce484222325");
+        assert_eq!(checksum("a"), "fnv1a64:af63dc4c8601ec8c");
+        assert_ne!(checksum("1\n2\n"), checksum("1\n2"));
+    }
+
+    #[test]
+    fn accepts_inputs() {
+        assert_eq!(validate_input("1\n2\n3\n"), Ok(()));
+        assert_eq!(validate_input("<<>>\n"), Ok(()));
+    }
+
+    #[test]
+    fn rejects_invalid_inputs() {
+        assert!(validate_input("").is_err());
+        assert!(validate_input("\n").is_err());
+        assert!(validate_input("1\n2").is_err());
+        assert!(validate_input("<!DOCTYPE html>\n<html></html>\n").is_err());
+        assert!(
+            validate_input(
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn roundtrips_manifests() {
+        let manifest = InputManifest::new("1\n2\n", 1_765_170_000);
+        let json = JsonValue::from(&manifest).stringify().unwrap();
+        assert_eq!(InputManifest::try_from(json).unwrap(), manifest);
+        assert_eq!(manifest.bytes, 4);
+        assert_eq!(manifest.formatted_date(), "2025-12-08 05:00");
+    }
+}
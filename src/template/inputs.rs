@@ -0,0 +1,141 @@
+//! Selects the puzzle inputs a solution runs against.
+//! Next to the default `data/{year}/inputs/{day}.txt`, a day can have named inputs in `data/{year}/inputs/{day}/{name}.txt`.
+//! They are selected by passing `--input <name>` or `--all-inputs` to the solution.
+
+use std::{env, fs, path::PathBuf, process};
+
+use crate::template::{Puzzle, read_file};
+
+/// A puzzle input, `name` is `None` for the default input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Input {
+    pub name: Option<String>,
+    pub contents: String,
+}
+
+impl Input {
+    /// Human readable name, `default` for the default input.
+    pub fn label(&self) -> &str {
+        self.name.as_deref().unwrap_or("default")
+    }
+}
+
+fn get_named_inputs_path(puzzle: Puzzle) -> PathBuf {
+    env::current_dir()
+        .unwrap()
+        .join("data")
+        .join(puzzle.year.to_string())
+        .join("inputs")
+        .join(puzzle.day.to_string())
+}
+
+fn get_default_input_path(puzzle: Puzzle) -> PathBuf {
+    env::current_dir()
+        .unwrap()
+        .join(puzzle.data_path("inputs", "txt"))
+}
+
+/// Names of the inputs in `data/{year}/inputs/{day}/`, sorted alphabetically.
+pub fn named_inputs(puzzle: Puzzle) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(get_named_inputs_path(puzzle)) else {
+        return vec![];
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+        .collect();
+
+    names.sort_unstable();
+    names
+}
+
+/// Read the named input `name` for `puzzle`.
+pub fn read_named_input(puzzle: Puzzle, name: &str) -> Result<String, String> {
+    let path = get_named_inputs_path(puzzle).join(format!("{name}.txt"));
+
+    fs::read_to_string(&path).map_err(|_| {
+        let available = named_inputs(puzzle);
+        if available.is_empty() {
+            format!("Could not open input \"{}\".", path.display())
+        } else {
+            format!(
+                "Could not open input \"{}\". Available inputs for day {}: {}.",
+                path.display(),
+                puzzle.day,
+                available.join(", ")
+            )
+        }
+    })
+}
+
+/// Inputs selected by the command-line arguments of the solution:
+///  1. `--input <name>` selects a single named input.
+///  2. `--all-inputs` selects the default input, if present, and all named inputs.
+///  3. without either flag, the default input is used.
+///
+/// Only answers for the default input can be submitted, since other inputs belong to other accounts.
+pub fn from_args(puzzle: Puzzle) -> Vec<Input> {
+    let args: Vec<String> = env::args().collect();
+
+    if args.iter().any(|x| x == "--submit")
+        && args.iter().any(|x| x == "--input" || x == "--all-inputs")
+    {
+        eprintln!(
+            "`--submit` can not be combined with `--input` or `--all-inputs`, only answers for the default input can be submitted."
+        );
+        process::exit(1);
+    }
+
+    let selected = if let Some(index) = args.iter().position(|x| x == "--input") {
+        let Some(name) = args.get(index + 1) else {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --input <name>");
+            process::exit(1);
+        };
+        read_named_input(puzzle, name).map(|contents| {
+            vec![Input {
+                name: Some(name.clone()),
+                contents,
+            }]
+        })
+    } else if args.iter().any(|x| x == "--all-inputs") {
+        all_inputs(puzzle)
+    } else {
+        return vec![Input {
+            name: None,
+            contents: read_file("inputs", puzzle),
+        }];
+    };
+
+    selected.unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    })
+}
+
+fn all_inputs(puzzle: Puzzle) -> Result<Vec<Input>, String> {
+    let mut inputs = vec![];
+
+    if let Ok(contents) = fs::read_to_string(get_default_input_path(puzzle)) {
+        inputs.push(Input {
+            name: None,
+            contents,
+        });
+    }
+
+    for name in named_inputs(puzzle) {
+        let contents = read_named_input(puzzle, &name)?;
+        inputs.push(Input {
+            name: Some(name),
+            contents,
+        });
+    }
+
+    if inputs.is_empty() {
+        return Err(format!("No inputs found for day {}.", puzzle.day));
+    }
+
+    Ok(inputs)
+}
@@ -0,0 +1,314 @@
+//! Private leaderboards and their terminal rendering.
+//! The leaderboard JSON is cached in `data/{year}/leaderboards/{id}.json`, since it should not be requested more than once every 15 minutes.
+
+use std::{collections::BTreeMap, collections::HashMap, fs, str::FromStr, time::Duration};
+use tinyjson::JsonValue;
+
+use crate::template::unlock::{format_countdown, unlock_timestamp};
+use crate::template::{Day, Puzzle, Year};
+
+/// Minimum age of a cached leaderboard before it is fetched again.
+pub const CACHE_DURATION: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Leaderboard {
+    pub members: Vec<Member>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Member {
+    pub id: u64,
+    /// `None` for anonymous users.
+    pub name: Option<String>,
+    pub local_score: u64,
+    pub stars: u64,
+    /// Seconds since the unix epoch at which the stars of part one and two were earned.
+    pub completions: BTreeMap<Day, [Option<u64>; 2]>,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+}
+
+pub fn get_cache_path(year: Year, id: u64) -> String {
+    format!("data/{year}/leaderboards/{id}.json")
+}
+
+/// Read the cached leaderboard and its age, if it was cached before.
+pub fn read_cache(year: Year, id: u64) -> Option<(String, Duration)> {
+    let path = get_cache_path(year, id);
+    let age = fs::metadata(&path).ok()?.modified().ok()?.elapsed().ok()?;
+    let json = fs::read_to_string(&path).ok()?;
+    Some((json, age))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Render the ranking followed by a table per day, listing when each member earned the stars of that day.
+/// Star times are shown relative to the unlock of the puzzle.
+pub fn render(leaderboard: &Leaderboard, year: Year, day: Option<Day>) -> String {
+    let mut members: Vec<&Member> = leaderboard.members.iter().collect();
+    members.sort_by(|a, b| {
+        b.local_score
+            .cmp(&a.local_score)
+            .then(b.stars.cmp(&a.stars))
+            .then(a.id.cmp(&b.id))
+    });
+
+    let ranking: Vec<Vec<String>> = members
+        .iter()
+        .enumerate()
+        .map(|(i, member)| {
+            vec![
+                format!("{}", i + 1),
+                member.display_name(),
+                member.local_score.to_string(),
+                member.stars.to_string(),
+            ]
+        })
+        .collect();
+
+    let mut sections = vec![format_table(&["#", "Name", "Score", "Stars"], &ranking)];
+
+    let days: Vec<Day> = year
+        .days()
+        .filter(|d| day.is_none_or(|day| day == *d))
+        .filter(|d| members.iter().any(|m| m.completions.contains_key(d)))
+        .collect();
+
+    for day in days {
+        let unlock = unlock_timestamp(Puzzle::new(year, day));
+
+        let mut completions: Vec<(&Member, [Option<u64>; 2])> = members
+            .iter()
+            .filter_map(|member| Some((*member, *member.completions.get(&day)?)))
+            .collect();
+        // NOTE: members that earned both stars rank first, by the time of their second star.
+        completions.sort_by_key(|(_, [part_1, part_2])| (part_2.is_none(), *part_2, *part_1));
+
+        let format_star = |ts: Option<u64>| {
+            ts.map_or("-".into(), |ts| format_countdown(ts.saturating_sub(unlock)))
+        };
+
+        let rows: Vec<Vec<String>> = completions
+            .iter()
+            .map(|(member, [part_1, part_2])| {
+                let delta = part_1.zip(*part_2).map_or("-".into(), |(p1, p2)| {
+                    format_countdown(p2.saturating_sub(p1))
+                });
+                vec![
+                    member.display_name(),
+                    format_star(*part_1),
+                    format_star(*part_2),
+                    delta,
+                ]
+            })
+            .collect();
+
+        sections.push(format!(
+            "Day {}\n{}",
+            day.into_inner(),
+            format_table(&["Name", "Part 1", "Part 2", "Delta"], &rows)
+        ));
+    }
+
+    sections.join("\n")
+}
+
+fn format_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |cells: &[String]| {
+        let line = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        format!("{}\n", line.trim_end())
+    };
+
+    let header: Vec<String> = header.iter().map(ToString::to_string).collect();
+    let separator: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+
+    let mut table = format_row(&header);
+    table.push_str(&format_row(&separator));
+    for row in rows {
+        table.push_str(&format_row(row));
+    }
+    table
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl TryFrom<String> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_members = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("members")
+            .ok_or("expected JSON document to have key `members`.")?
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected `json.members` to be an object.")?;
+
+        Ok(Leaderboard {
+            members: json_members
+                .values()
+                .map(Member::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<&JsonValue> for Member {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected member to be an object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .map(|v| *v as u64)
+                .ok_or(format!("expected member.{key} to be a number."))
+        };
+
+        let name = match json.get("name") {
+            Some(JsonValue::String(name)) => Some(name.clone()),
+            _ => None,
+        };
+
+        let mut completions = BTreeMap::new();
+
+        if let Some(days) = json
+            .get("completion_day_level")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+        {
+            for (day, parts) in days {
+                let day = day
+                    .parse()
+                    .ok()
+                    .and_then(Day::new)
+                    .ok_or(format!("expected `{day}` to be a day."))?;
+
+                let parts = parts
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("expected member.completion_day_level entries to be objects.")?;
+
+                let get_star_ts = |part: &str| {
+                    parts
+                        .get(part)
+                        .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+                        .and_then(|v| v.get("get_star_ts"))
+                        .and_then(|v| v.get::<f64>())
+                        .map(|ts| *ts as u64)
+                };
+
+                completions.insert(day, [get_star_ts("1"), get_star_ts("2")]);
+            }
+        }
+
+        Ok(Member {
+            id: number("id")?,
+            name,
+            local_score: number("local_score")?,
+            stars: number("stars")?,
+            completions,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{Leaderboard, render};
+    use crate::{day, year};
+
+    /// 2025-12-01T05:00:00Z, the unlock of day 1.
+    const DAY_1_UNLOCK: u64 = 1_764_565_200;
+
+    fn get_mock_json() -> String {
+        format!(
+            r#"{{
+                "event": "2025",
+                "owner_id": 1,
+                "members": {{
+                    "1": {{ "id": 1, "name": "alice", "stars": 2, "local_score": 4, "global_score": 0,
+                        "completion_day_level": {{
+                            "1": {{ "1": {{ "get_star_ts": {}, "star_index": 1 }}, "2": {{ "get_star_ts": {}, "star_index": 3 }} }}
+                        }} }},
+                    "2": {{ "id": 2, "name": null, "stars": 1, "local_score": 2, "global_score": 0,
+                        "completion_day_level": {{
+                            "1": {{ "1": {{ "get_star_ts": {}, "star_index": 2 }} }}
+                        }} }},
+                    "3": {{ "id": 3, "name": "carol", "stars": 0, "local_score": 0, "global_score": 0,
+                        "completion_day_level": {{}} }}
+                }}
+            }}"#,
+            DAY_1_UNLOCK + 312,
+            DAY_1_UNLOCK + 580,
+            DAY_1_UNLOCK + 90_000
+        )
+    }
+
+    #[test]
+    fn parses_leaderboards() {
+        let leaderboard = Leaderboard::try_from(get_mock_json()).unwrap();
+        assert_eq!(leaderboard.members.len(), 3);
+
+        let alice = leaderboard.members.iter().find(|m| m.id == 1).unwrap();
+        assert_eq!(alice.name.as_deref(), Some("alice"));
+        assert_eq!(alice.local_score, 4);
+        assert_eq!(
+            alice.completions.get(&day!(1)),
+            Some(&[Some(DAY_1_UNLOCK + 312), Some(DAY_1_UNLOCK + 580)])
+        );
+
+        let anonymous = leaderboard.members.iter().find(|m| m.id == 2).unwrap();
+        assert_eq!(anonymous.display_name(), "(anonymous user #2)");
+    }
+
+    #[test]
+    fn rejects_invalid_leaderboards() {
+        assert!(Leaderboard::try_from("<html></html>".to_string()).is_err());
+        assert!(Leaderboard::try_from(r#"{ "event": "2025" }"#.to_string()).is_err());
+    }
+
+    #[test]
+    fn renders_leaderboards() {
+        let leaderboard = Leaderboard::try_from(get_mock_json()).unwrap();
+        let expected = [
+            "#  Name                 Score  Stars",
+            "-  -------------------  -----  -----",
+            "1  alice                4      2",
+            "2  (anonymous user #2)  2      1",
+            "3  carol                0      0",
+            "",
+            "Day 1",
+            "Name                 Part 1       Part 2    Delta",
+            "-------------------  -----------  --------  --------",
+            "alice                00:05:12     00:09:40  00:04:28",
+            "(anonymous user #2)  1d 01:00:00  -         -",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(render(&leaderboard, year!(2025), None), expected);
+        assert!(!render(&leaderboard, year!(2025), Some(day!(2))).contains("Day 1"));
+    }
+}
@@ -0,0 +1,133 @@
+//! Moves files of the layout before data was namespaced by year to the configured year, once.
+//! Data in `data/{folder}/`, e.g. `data/inputs/01.txt`, moves to `data/{year}/{folder}/`, and solutions
+//! in `src/bin/{day}.rs` are renamed to `src/bin/{year}-{day}.rs` and updated to the `solution!(year, day)` form.
+//! Files that already exist at their new path are never overwritten.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::template::{Day, Puzzle, Year};
+
+static LEGACY_FOLDERS: [&str; 5] = ["inputs", "puzzles", "examples", "answers", "submissions"];
+static BIN_DIR: &str = "src/bin";
+
+/// Move all legacy data and solutions to `year`. Does nothing if there are none.
+pub fn migrate(year: Year) {
+    for folder in LEGACY_FOLDERS {
+        let legacy = Path::new("data").join(folder);
+        let Ok(entries) = fs::read_dir(&legacy) else {
+            continue;
+        };
+
+        let target = Path::new("data").join(year.to_string()).join(folder);
+
+        for entry in entries.flatten() {
+            // NOTE: `.keep` files only exist to commit the empty folders.
+            if entry.file_name() == ".keep" {
+                continue;
+            }
+            move_path(&entry.path(), &target.join(entry.file_name()));
+        }
+    }
+
+    let Ok(entries) = fs::read_dir(BIN_DIR) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let puzzle = path
+            .extension()
+            .filter(|ext| *ext == "rs")
+            .and(path.file_stem())
+            .and_then(|stem| stem.to_str())
+            .filter(|stem| stem.len() == 2)
+            .and_then(|stem| stem.parse::<Day>().ok())
+            .and_then(|day| Puzzle::checked(year, day).ok());
+
+        if let Some(puzzle) = puzzle {
+            migrate_bin(&path, puzzle);
+        }
+    }
+}
+
+fn move_path(from: &Path, to: &Path) {
+    if to.exists() {
+        eprintln!(
+            "Not moving \"{}\", \"{}\" already exists.",
+            from.display(),
+            to.display()
+        );
+        return;
+    }
+
+    let result = to
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::rename(from, to));
+
+    match result {
+        Ok(()) => println!("Moved \"{}\" to \"{}\".", from.display(), to.display()),
+        Err(e) => eprintln!("Failed to move \"{}\": {e}", from.display()),
+    }
+}
+
+fn migrate_bin(path: &Path, puzzle: Puzzle) {
+    let target = PathBuf::from(puzzle.bin_path());
+    let Ok(contents) = fs::read_to_string(path) else {
+        return;
+    };
+
+    if target.exists() {
+        eprintln!(
+            "Not moving \"{}\", \"{}\" already exists.",
+            path.display(),
+            target.display()
+        );
+        return;
+    }
+
+    let result = fs::write(&target, update_solution(&contents, puzzle.year))
+        .and_then(|()| fs::remove_file(path));
+
+    match result {
+        Ok(()) => println!("Moved \"{}\" to \"{}\".", path.display(), target.display()),
+        Err(e) => eprintln!("Failed to move \"{}\": {e}", path.display()),
+    }
+}
+
+/// Update a solution to the `solution!(year, day)` macro and the `PUZZLE` constant that replaced `DAY`.
+fn update_solution(contents: &str, year: Year) -> String {
+    contents
+        .replacen("solution!(", &format!("solution!({year}, "), 1)
+        .replace(", DAY)", ", PUZZLE)")
+        .replace(", DAY,", ", PUZZLE,")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::update_solution;
+    use crate::year;
+
+    #[test]
+    fn updates_solutions() {
+        let contents = [
+            "advent_of_code::solution!(8, parse);",
+            "let result = part_one(&advent_of_code::template::read_file(\"examples\", DAY));",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            update_solution(&contents, year!(2024)),
+            [
+                "advent_of_code::solution!(2024, 8, parse);",
+                "let result = part_one(&advent_of_code::template::read_file(\"examples\", PUZZLE));",
+            ]
+            .join("\n")
+        );
+    }
+}
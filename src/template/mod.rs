@@ -1,68 +1,191 @@
 use std::{env, fs};
 
+pub mod alloc;
 pub mod aoc_cli;
+pub mod aoc_client;
 pub mod commands;
+pub mod inputs;
+pub mod legacy_layout;
+pub mod named_examples;
 pub mod runner;
+pub mod unlock;
 
+pub use answer::{Answer, AsciiArt, WithDiagnostics};
 pub use day::*;
+pub use outcome::{InputError, NotImplemented, Outcome, PartOutput, parse_lines};
+pub use puzzle::*;
+pub use year::*;
 
+mod answer;
+mod answers;
+mod date;
 mod day;
+mod examples;
+mod history;
+mod html;
+mod input_cache;
+mod leaderboard;
+mod ocr;
+mod outcome;
+mod protocol;
+mod puzzle;
 mod readme_benchmarks;
+mod readme_stars;
 mod run_multi;
+mod stats;
+mod submissions;
+mod templates;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
+pub const ANSI_RED: &str = "\x1b[31m";
+pub const ANSI_GREEN: &str = "\x1b[32m";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: Puzzle) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
+    let filepath = cwd.join(puzzle.data_path(folder, "txt"));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: Puzzle, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
         .join("data")
+        .join(puzzle.year.to_string())
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
+        .join(format!("{}-{part}.txt", puzzle.day));
     let f = fs::read_to_string(filepath);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Creates the constant `PUZZLE` and sets up the input and runner for each part.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The first two parameters are the year and the day of the puzzle, e.g. `solution!(2025, 8)`.
+/// The optional, third parameter (1 or 2) allows you to only run a single part of the solution.
+///
+/// The solution runs against `data/{year}/inputs/{day}.txt` by default. Named inputs in `data/{year}/inputs/{day}/`
+/// are selected with `--input <name>` or `--all-inputs`, see [`inputs::from_args`].
+///
+/// Passing `parse` as third parameter (e.g. `solution!(2025, 8, parse)`) runs the day's `parse` function once,
+/// and passes a reference to its output to both parts. Parsing is then timed separately.
 #[macro_export]
 macro_rules! solution {
-    ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+    ($year:expr, $day:expr) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1] [part_two, 2]);
+    };
+    ($year:expr, $day:expr, 1) => {
+        $crate::solution!(@impl $year, $day, [part_one, 1]);
+    };
+    ($year:expr, $day:expr, 2) => {
+        $crate::solution!(@impl $year, $day, [part_two, 2]);
+    };
+    ($year:expr, $day:expr, parse) => {
+        $crate::solution!(@impl_parsed $year, $day, [part_one, 1] [part_two, 2]);
     };
-    ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+    ($year:expr, $day:expr, parse, 1) => {
+        $crate::solution!(@impl_parsed $year, $day, [part_one, 1]);
+    };
+    ($year:expr, $day:expr, parse, 2) => {
+        $crate::solution!(@impl_parsed $year, $day, [part_two, 2]);
+    };
+
+    (@impl $year:expr, $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@preamble $year, $day);
+
+        fn main() {
+            use $crate::template::runner::*;
+            for_each_input(PUZZLE, |input, name| {
+                $( run_part($func, input, PUZZLE, $part, name); )*
+            });
+        }
     };
-    ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+
+    (@impl_parsed $year:expr, $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@preamble $year, $day);
+
+        fn main() {
+            use $crate::template::runner::*;
+            for_each_input(PUZZLE, |input, name| {
+                let parsed = run_parse(parse, input, name);
+                $( run_part($func, &parsed, PUZZLE, $part, name); )*
+            });
+        }
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
-        /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+    (@preamble $year:expr, $day:expr) => {
+        /// The current puzzle.
+        const PUZZLE: $crate::template::Puzzle = $crate::puzzle!($year, $day);
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
         static ALLOC: dhat::Alloc = dhat::Alloc;
 
-        fn main() {
-            use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+        #[cfg(all(feature = "alloc-stats", not(feature = "dhat-heap")))]
+        #[global_allocator]
+        static ALLOC: $crate::template::alloc::CountingAlloc = $crate::template::alloc::CountingAlloc;
+    };
+}
+
+/// Generates a test for each named example in `data/{year}/examples/{day}/`, see [`named_examples`].
+/// Each test checks the parts that have an expected answer in the examples' `manifest.json`,
+/// and fails if the manifest lists no answer for the example at all.
+///
+/// The names of the examples follow the same optional parameters as [`solution!`], e.g.
+/// `examples!(small, larger)`, `examples!(parse; small, larger)` or `examples!(1; small)`.
+/// Use it in the `tests` module of a solution, the tests are then named like `tests::examples::small`.
+#[macro_export]
+macro_rules! examples {
+    (parse, 1; $($name:ident),+ $(,)?) => {
+        $crate::examples!(@impl parse [$($name)+] [[part_one, 1]]);
+    };
+    (parse, 2; $($name:ident),+ $(,)?) => {
+        $crate::examples!(@impl parse [$($name)+] [[part_two, 2]]);
+    };
+    (parse; $($name:ident),+ $(,)?) => {
+        $crate::examples!(@impl parse [$($name)+] [[part_one, 1] [part_two, 2]]);
+    };
+    (1; $($name:ident),+ $(,)?) => {
+        $crate::examples!(@impl raw [$($name)+] [[part_one, 1]]);
+    };
+    (2; $($name:ident),+ $(,)?) => {
+        $crate::examples!(@impl raw [$($name)+] [[part_two, 2]]);
+    };
+    ($($name:ident),+ $(,)?) => {
+        $crate::examples!(@impl raw [$($name)+] [[part_one, 1] [part_two, 2]]);
+    };
+
+    (@impl $mode:ident [$($name:ident)+] $parts:tt) => {
+        mod examples {
+            use super::*;
+
+            $( $crate::examples!(@test $mode $name $parts); )+
+        }
+    };
+
+    (@test raw $name:ident [$( [$func:ident, $part:expr] )+]) => {
+        #[test]
+        fn $name() {
+            let example = $crate::template::named_examples::Example::read(PUZZLE, stringify!($name));
+            let checked = [$( example.check($part, || $func(&example.contents)) ),+];
+            assert!(checked.contains(&true), "no expected answers for example `{}` in the manifest", example.name);
+        }
+    };
+
+    (@test parse $name:ident [$( [$func:ident, $part:expr] )+]) => {
+        #[test]
+        fn $name() {
+            let example = $crate::template::named_examples::Example::read(PUZZLE, stringify!($name));
+            let parsed = parse(&example.contents);
+            let checked = [$( example.check($part, || $func(&parsed)) ),+];
+            assert!(checked.contains(&true), "no expected answers for example `{}` in the manifest", example.name);
         }
     };
 }
@@ -0,0 +1,179 @@
+//! Named examples of a day, stored as `data/{year}/examples/{day}/{name}.txt`.
+//! Their expected answers are listed in `data/{year}/examples/{day}/manifest.json`, e.g.:
+//!
+//! ```json
+//! {
+//!     "small": { "part_1": "2", "part_2": "4" },
+//!     "larger": { "part_2": "31" }
+//! }
+//! ```
+//!
+//! Tests for them are generated with the [`examples!`](crate::examples) macro.
+
+use std::{collections::HashMap, env, fs, path::PathBuf, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{
+    Puzzle,
+    outcome::{Outcome, PartOutput},
+};
+
+/// Largest integer that a JSON number holds without losing precision.
+const MAX_SAFE_INTEGER: f64 = 9_007_199_254_740_991.0;
+
+/// An example and its expected answers per part, `None` if the manifest does not list one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub contents: String,
+    pub expected: [Option<String>; 2],
+}
+
+fn get_examples_path(puzzle: Puzzle) -> PathBuf {
+    env::current_dir()
+        .unwrap()
+        .join("data")
+        .join(puzzle.year.to_string())
+        .join("examples")
+        .join(puzzle.day.to_string())
+}
+
+impl Example {
+    /// Read the example `name` of `puzzle` together with its expected answers.
+    /// Panics if the example or the manifest can not be read, since it is meant to be used in tests.
+    #[must_use]
+    pub fn read(puzzle: Puzzle, name: &str) -> Self {
+        let dir = get_examples_path(puzzle);
+        let path = dir.join(format!("{name}.txt"));
+        let contents = fs::read_to_string(&path)
+            .unwrap_or_else(|_| panic!("could not open example file \"{}\"", path.display()));
+
+        let manifest_path = dir.join("manifest.json");
+        let mut manifest = match fs::read_to_string(&manifest_path) {
+            Ok(json) => parse_manifest(&json).unwrap_or_else(|e| {
+                panic!("invalid manifest \"{}\": {e}", manifest_path.display())
+            }),
+            Err(_) => HashMap::new(),
+        };
+
+        Example {
+            name: name.into(),
+            contents,
+            expected: manifest.remove(name).unwrap_or_default(),
+        }
+    }
+
+    /// Compare the result of `solve` to the expected answer of `part`, failing with the error chain of failed parts.
+    /// Parts without an expected answer are not solved, returns whether the part was checked.
+    #[track_caller]
+    pub fn check<R: PartOutput>(&self, part: u8, solve: impl FnOnce() -> R) -> bool {
+        let Some(expected) = &self.expected[usize::from(part) - 1] else {
+            return false;
+        };
+
+        let result = match solve().into_outcome() {
+            Outcome::Solved { answer, .. } => Some(answer),
+            Outcome::NotImplemented => None,
+            Outcome::Failed(error) => {
+                panic!("part {part} of example `{}` failed:\n{error}", self.name)
+            }
+        };
+        assert_eq!(
+            result.as_deref(),
+            Some(expected.as_str()),
+            "part {part} of example `{}`",
+            self.name
+        );
+        true
+    }
+}
+
+/// Expected answers per example name.
+fn parse_manifest(json: &str) -> Result<HashMap<String, [Option<String>; 2]>, String> {
+    let json = JsonValue::from_str(json).or(Err("not valid JSON file."))?;
+
+    let examples = json
+        .get::<HashMap<String, JsonValue>>()
+        .ok_or("expected JSON document to be an object.")?;
+
+    examples
+        .iter()
+        .map(|(name, parts)| {
+            let parts = parts
+                .get::<HashMap<String, JsonValue>>()
+                .ok_or(format!("expected `{name}` to be an object."))?;
+
+            let get_answer = |key: &str| match parts.get(key) {
+                None | Some(JsonValue::Null) => Ok(None),
+                Some(JsonValue::String(answer)) => Ok(Some(answer.clone())),
+                // NOTE: numbers are parsed as floats, larger answers have to be strings.
+                Some(JsonValue::Number(answer))
+                    if answer.fract() == 0.0 && answer.abs() <= MAX_SAFE_INTEGER =>
+                {
+                    Ok(Some(format!("{}", *answer as i64)))
+                }
+                Some(_) => Err(format!(
+                    "expected `{name}.{key}` to be a string or an integer."
+                )),
+            };
+
+            Ok((name.clone(), [get_answer("part_1")?, get_answer("part_2")?]))
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{Example, parse_manifest};
+
+    #[test]
+    fn parses_manifests() {
+        let manifest = parse_manifest(
+            r#"{ "small": { "part_1": "abc", "part_2": 4 }, "larger": { "part_1": null, "part_2": "12345678901234567890" } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            manifest.get("small"),
+            Some(&[Some("abc".into()), Some("4".into())])
+        );
+        assert_eq!(
+            manifest.get("larger"),
+            Some(&[None, Some("12345678901234567890".into())])
+        );
+
+        assert!(parse_manifest(r#"{ "small": { "part_1": 1.5 } }"#).is_err());
+        assert!(parse_manifest(r#"{ "small": { "part_1": 12345678901234567890 } }"#).is_err());
+        assert!(parse_manifest("[]").is_err());
+    }
+
+    fn get_example() -> Example {
+        Example {
+            name: "small".into(),
+            contents: "1\n2\n".into(),
+            expected: [Some("2".into()), None],
+        }
+    }
+
+    #[test]
+    fn checks_expected_answers() {
+        let example = get_example();
+        assert!(example.check(1, || Some(example.contents.lines().count())));
+        assert!(!example.check(2, || -> Option<u64> { panic!("not solved") }));
+        assert!(example.check(1, || Ok::<_, String>(example.contents.lines().count())));
+    }
+
+    #[test]
+    #[should_panic(expected = "part 1 of example `small` failed:\nerror: no path found")]
+    fn reports_failed_parts() {
+        get_example().check(1, || -> Result<u64, String> { Err("no path found".into()) });
+    }
+
+    #[test]
+    #[should_panic(expected = "part 1 of example `small`")]
+    fn rejects_wrong_answers() {
+        get_example().check(1, || Some(3));
+    }
+}
@@ -0,0 +1,153 @@
+//! Reads letters drawn in the font of Advent of Code puzzles, e.g. the screen of 2016 day 8 or the paper of 2021 day 13.
+//! Letters are 6 pixels high and separated by at least one empty column. Lit pixels are `#` or `█`, others `.` or blank.
+
+static FONT: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', ["###", ".#.", ".#.", ".#.", ".#.", "###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+const HEIGHT: usize = 6;
+
+fn is_lit(c: char) -> bool {
+    matches!(c, '#' | '█' | '▓' | '■')
+}
+
+/// Columns of a glyph as bitmasks, the top row being the lowest bit.
+type Glyph = Vec<u8>;
+
+fn to_columns(rows: &[Vec<bool>]) -> Vec<u8> {
+    let width = rows.iter().map(Vec::len).max().unwrap_or_default();
+    (0..width)
+        .map(|x| {
+            rows.iter().enumerate().fold(0, |column, (y, row)| {
+                column | (u8::from(row.get(x).copied().unwrap_or_default()) << y)
+            })
+        })
+        .collect()
+}
+
+/// Split the columns of a line of letters into glyphs at empty columns.
+fn split_glyphs(columns: &[u8]) -> Vec<(usize, Glyph)> {
+    let mut glyphs = vec![];
+    let mut current: Option<(usize, Glyph)> = None;
+
+    for (x, column) in columns.iter().enumerate() {
+        match (&mut current, *column) {
+            (Some(_), 0) => glyphs.extend(current.take()),
+            (None, 0) => {}
+            (Some((_, glyph)), column) => glyph.push(column),
+            (None, column) => current = Some((x, vec![column])),
+        }
+    }
+
+    glyphs.extend(current);
+    glyphs
+}
+
+fn font_glyph(rows: &[&str; 6]) -> Glyph {
+    let rows: Vec<Vec<bool>> = rows
+        .iter()
+        .map(|row| row.chars().map(is_lit).collect())
+        .collect();
+    to_columns(&rows)
+}
+
+/// Read the letters of `art`. Leading and trailing blank lines are ignored.
+pub fn read_letters(art: &str) -> Result<String, String> {
+    let rows: Vec<Vec<bool>> = art
+        .lines()
+        .map(|line| line.chars().map(is_lit).collect::<Vec<_>>())
+        .skip_while(|row| !row.contains(&true))
+        .collect();
+    let height = rows.len()
+        - rows
+            .iter()
+            .rev()
+            .take_while(|row| !row.contains(&true))
+            .count();
+
+    if height != HEIGHT {
+        return Err(format!(
+            "expected letters to be {HEIGHT} pixels high, found {height} rows."
+        ));
+    }
+
+    let glyphs = split_glyphs(&to_columns(&rows[..height]));
+    if glyphs.is_empty() {
+        return Err("found no letters.".into());
+    }
+
+    glyphs
+        .into_iter()
+        .map(|(x, glyph)| {
+            FONT.iter()
+                .find(|(_, rows)| font_glyph(rows) == glyph)
+                .map(|(letter, _)| *letter)
+                .ok_or(format!("unknown letter at column {x}."))
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::read_letters;
+
+    #[test]
+    fn reads_letters() {
+        let art = [
+            "",
+            "#..#.####.###..#...#",
+            "#..#.#....#..#.#...#",
+            "####.###..###...#.#.",
+            "#..#.#....#..#...#..",
+            "#..#.#....#..#...#..",
+            "#..#.####.###....#..",
+            "",
+        ]
+        .join("\n");
+        assert_eq!(read_letters(&art).as_deref(), Ok("HEBY"));
+    }
+
+    #[test]
+    fn reads_blocks_and_spacing() {
+        let art = [
+            " ██   ███ ",
+            "█  █  █  █",
+            "█     ███ ",
+            "█ ██  █  █",
+            "█  █  █  █",
+            " ███  ███ ",
+        ]
+        .join("\n");
+        assert_eq!(read_letters(&art).as_deref(), Ok("GB"));
+    }
+
+    #[test]
+    fn rejects_unknown_letters() {
+        let art = ["#.#", ".#.", "#.#", ".#.", "#.#", ".#."].join("\n");
+        assert_eq!(
+            read_letters(&art),
+            Err("unknown letter at column 0.".into())
+        );
+        assert!(read_letters("###\n###").is_err());
+        assert!(read_letters("").is_err());
+    }
+}
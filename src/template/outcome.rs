@@ -0,0 +1,188 @@
+//! Return types of solution parts. Parts return `Option<T>`, where `None` means the part is not implemented yet,
+//! or `Result<T, E>` to report why they failed. Failures are printed with their error chain and the input line they occurred at.
+
+use std::{error::Error, fmt::Display};
+
+use crate::template::answer::Answer;
+
+/// How a solution part ended.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Solved {
+        answer: String,
+        diagnostics: Option<String>,
+    },
+    NotImplemented,
+    /// The part returned an error, formatted by [`report`].
+    Failed(String),
+}
+
+impl Outcome {
+    fn solved(answer: &impl Answer) -> Self {
+        Outcome::Solved {
+            answer: answer.to_answer(),
+            diagnostics: answer.diagnostics(),
+        }
+    }
+}
+
+/// Return types that the `solution!` macro accepts for parts.
+pub trait PartOutput {
+    fn into_outcome(self) -> Outcome;
+}
+
+impl<T: Answer> PartOutput for Option<T> {
+    fn into_outcome(self) -> Outcome {
+        match self {
+            Some(answer) => Outcome::solved(&answer),
+            None => Outcome::NotImplemented,
+        }
+    }
+}
+
+impl<T: Answer, E: Into<Box<dyn Error>>> PartOutput for Result<T, E> {
+    fn into_outcome(self) -> Outcome {
+        match self {
+            Ok(answer) => Outcome::solved(&answer),
+            Err(e) => {
+                let e: Box<dyn Error> = e.into();
+                if e.is::<NotImplemented>() {
+                    Outcome::NotImplemented
+                } else {
+                    Outcome::Failed(report(e.as_ref()))
+                }
+            }
+        }
+    }
+}
+
+/// Format `error` and its sources, followed by the input line of the first [`InputError`] in the chain.
+pub fn report(error: &(dyn Error + 'static)) -> String {
+    let chain: Vec<&(dyn Error + 'static)> =
+        std::iter::successors(Some(error), |e| (*e).source()).collect();
+
+    let mut lines: Vec<String> = chain
+        .iter()
+        .enumerate()
+        .map(|(i, e)| match i {
+            0 => format!("error: {e}"),
+            _ => format!("caused by: {e}"),
+        })
+        .collect();
+
+    if let Some(input_error) = chain.iter().find_map(|e| e.downcast_ref::<InputError>()) {
+        lines.push(format!("{:>5} | {}", input_error.line, input_error.text));
+    }
+
+    lines.join("\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Error for parts that return a `Result` but are not implemented yet, e.g. `Err(NotImplemented)?`.
+/// It is reported like `None` of parts that return an `Option`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NotImplemented;
+
+impl Display for NotImplemented {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("not implemented")
+    }
+}
+
+impl Error for NotImplemented {}
+
+/// An error caused by a line of the input. The line is printed below the error chain.
+#[derive(Debug)]
+pub struct InputError {
+    /// Line number, starting at 1.
+    pub line: usize,
+    pub text: String,
+    pub source: Box<dyn Error>,
+}
+
+impl InputError {
+    pub fn new(line: usize, text: &str, source: impl Into<Box<dyn Error>>) -> Self {
+        Self {
+            line,
+            text: text.into(),
+            source: source.into(),
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid input at line {}", self.line)
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+/// Parse each line of `input` with `parse`, failing with the [`InputError`] of the first line that can not be parsed.
+pub fn parse_lines<T, E: Into<Box<dyn Error>>>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, E>,
+) -> Result<Vec<T>, InputError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|e| InputError::new(i + 1, line, e)))
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{NotImplemented, Outcome, PartOutput, parse_lines};
+
+    #[test]
+    fn distinguishes_unsolved_from_failed_parts() {
+        assert_eq!(
+            Some(42).into_outcome(),
+            Outcome::Solved {
+                answer: "42".into(),
+                diagnostics: None
+            }
+        );
+        assert_eq!(None::<u64>.into_outcome(), Outcome::NotImplemented);
+
+        let result: Result<u64, NotImplemented> = Err(NotImplemented);
+        assert_eq!(result.into_outcome(), Outcome::NotImplemented);
+
+        let result: Result<u64, String> = Err("no path found".into());
+        assert_eq!(
+            result.into_outcome(),
+            Outcome::Failed("error: no path found".into())
+        );
+    }
+
+    #[test]
+    fn reports_error_chains_with_line_context() {
+        let result = parse_lines("1\n2\n3a\n4", str::parse::<u64>);
+        assert_eq!(result.as_ref().unwrap_err().line, 3);
+
+        let expected = [
+            "error: invalid input at line 3",
+            "caused by: invalid digit found in string",
+            "    3 | 3a",
+        ]
+        .join("\n");
+        let sum = result.map(|numbers| numbers.iter().sum::<u64>());
+        assert_eq!(sum.into_outcome(), Outcome::Failed(expected));
+
+        let sum = parse_lines("1\n2", str::parse::<u64>).map(|numbers| numbers.iter().sum::<u64>());
+        assert_eq!(
+            sum.into_outcome(),
+            Outcome::Solved {
+                answer: "3".into(),
+                diagnostics: None
+            }
+        );
+    }
+}
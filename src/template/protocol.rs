@@ -0,0 +1,383 @@
+//! Machine-readable output of solution binaries, see `--format json`.
+//! Each part is emitted as a single JSON record per line, which `run_multi` consumes.
+
+use std::{collections::HashMap, env, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::alloc::AllocStats;
+use crate::template::stats::BenchStats;
+
+/// Value of [`PartRecord::part`] for the shared parse phase of a solution.
+pub const PARSE_PART: u8 = 0;
+
+/// Human readable name of a part, e.g. `Part 1` or `Parse`.
+pub fn part_label(part: u8) -> String {
+    if part == PARSE_PART {
+        "Parse".into()
+    } else {
+        format!("Part {part}")
+    }
+}
+
+/// Returns `true` if the binary was invoked with `--format json`.
+pub fn is_json_output() -> bool {
+    let args: Vec<String> = env::args().collect();
+    args.windows(2)
+        .any(|w| w[0] == "--format" && w[1] == "json")
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PartStatus {
+    Solved,
+    Unsolved,
+    /// The part returned an error, see [`PartRecord::error`].
+    Failed,
+}
+
+impl PartStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            PartStatus::Solved => "solved",
+            PartStatus::Unsolved => "unsolved",
+            PartStatus::Failed => "failed",
+        }
+    }
+}
+
+impl FromStr for PartStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "solved" => Ok(PartStatus::Solved),
+            "unsolved" => Ok(PartStatus::Unsolved),
+            "failed" => Ok(PartStatus::Failed),
+            s => Err(format!("Unknown part status `{s}`.")),
+        }
+    }
+}
+
+/// The result of running a single solution part, or the parse phase shared by both parts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartRecord {
+    pub part: u8,
+    pub status: PartStatus,
+    pub answer: Option<String>,
+    pub stats: BenchStats,
+    /// Allocations of the part, only present with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+    /// Name of the input the part ran against, `None` for the default input.
+    pub input: Option<String>,
+    /// Known answer from `data/answers`, if present.
+    pub expected: Option<String>,
+    /// Output of the part that is not part of its answer, see [`Answer::diagnostics`](crate::template::Answer::diagnostics).
+    pub diagnostics: Option<String>,
+    /// Error chain of a failed part, see [`Outcome::Failed`](crate::template::Outcome::Failed).
+    pub error: Option<String>,
+}
+
+impl PartRecord {
+    pub fn new(part: u8, answer: Option<String>, stats: BenchStats) -> Self {
+        let status = if answer.is_some() {
+            PartStatus::Solved
+        } else {
+            PartStatus::Unsolved
+        };
+
+        Self {
+            part,
+            status,
+            answer,
+            stats,
+            alloc: None,
+            input: None,
+            expected: None,
+            diagnostics: None,
+            error: None,
+        }
+    }
+
+    /// The record of a part that returned an error.
+    pub fn failed(part: u8, error: String, stats: BenchStats) -> Self {
+        Self {
+            status: PartStatus::Failed,
+            error: Some(error),
+            ..Self::new(part, None, stats)
+        }
+    }
+
+    pub fn with_alloc(self, alloc: Option<AllocStats>) -> Self {
+        Self { alloc, ..self }
+    }
+
+    pub fn with_input(self, input: Option<&str>) -> Self {
+        Self {
+            input: input.map(Into::into),
+            ..self
+        }
+    }
+
+    pub fn with_expected(self, expected: Option<String>) -> Self {
+        Self { expected, ..self }
+    }
+
+    pub fn with_diagnostics(self, diagnostics: Option<String>) -> Self {
+        Self {
+            diagnostics,
+            ..self
+        }
+    }
+
+    /// Whether the answer matches the known answer, `None` if no answer is known.
+    pub fn is_correct(&self) -> Option<bool> {
+        self.expected
+            .as_ref()
+            .map(|expected| self.answer.as_ref() == Some(expected))
+    }
+
+    /// Serializes the record to a single line of JSON.
+    pub fn to_json_line(&self) -> String {
+        // NOTE: stringifying a JSON value built from strings and numbers can not fail.
+        JsonValue::from(self).stringify().unwrap()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartRecord> for JsonValue {
+    fn from(value: &PartRecord) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "status".into(),
+            JsonValue::String(value.status.as_str().into()),
+        );
+        map.insert(
+            "answer".into(),
+            value
+                .answer
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "duration_nanos".into(),
+            JsonValue::Number(value.stats.mean.as_nanos() as f64),
+        );
+        map.insert(
+            "samples".into(),
+            JsonValue::Number(value.stats.samples as f64),
+        );
+        map.insert("stats".into(), JsonValue::from(&value.stats));
+        map.insert(
+            "alloc".into(),
+            value
+                .alloc
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+        map.insert(
+            "input".into(),
+            value
+                .input
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "expected".into(),
+            value
+                .expected
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "diagnostics".into(),
+            value
+                .diagnostics
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+        map.insert(
+            "error".into(),
+            value
+                .error
+                .clone()
+                .map_or(JsonValue::Null, JsonValue::String),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&str> for PartRecord {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(value).or(Err("not valid JSON."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part record to be a JSON object.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>())
+            .map(|v| *v as u8)
+            .ok_or("Expected record.part to be a number.")?;
+
+        let status = json
+            .get("status")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected record.status to be a string.")?
+            .parse()?;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected record.answer to be null or string.")?;
+
+        let stats = json
+            .get("stats")
+            .ok_or("Expected record to have key `stats`.")
+            .map(BenchStats::try_from)??;
+
+        let alloc = match json.get("alloc") {
+            Some(v) if !v.is_null() => Some(AllocStats::try_from(v)?),
+            _ => None,
+        };
+
+        let optional_string = |key: &str| match json.get(key) {
+            Some(JsonValue::String(value)) => Ok(Some(value.clone())),
+            Some(JsonValue::Null) | None => Ok(None),
+            Some(_) => Err(format!("Expected record.{key} to be null or string.")),
+        };
+
+        Ok(PartRecord {
+            part,
+            status,
+            answer: answer.cloned(),
+            stats,
+            alloc,
+            input: optional_string("input")?,
+            expected: optional_string("expected")?,
+            diagnostics: optional_string("diagnostics")?,
+            error: optional_string("error")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{PartRecord, PartStatus};
+    use crate::template::{alloc::AllocStats, stats::BenchStats};
+    use std::time::Duration;
+
+    fn get_mock_stats() -> BenchStats {
+        BenchStats::from_samples(&mut [Duration::from_nanos(74), Duration::from_nanos(76)])
+    }
+
+    #[test]
+    fn roundtrips_records() {
+        let record = PartRecord::new(1, Some("42".into()), get_mock_stats());
+        let parsed = PartRecord::try_from(record.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed, record);
+        assert_eq!(parsed.status, PartStatus::Solved);
+    }
+
+    #[test]
+    fn roundtrips_unsolved_records() {
+        let record = PartRecord::new(2, None, get_mock_stats());
+        let parsed = PartRecord::try_from(record.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed.status, PartStatus::Unsolved);
+        assert_eq!(parsed.answer, None);
+    }
+
+    #[test]
+    fn preserves_answers_with_patterns() {
+        let answer = "@ ( 2s @ 5 samples)\n▼ line".to_string();
+        let record = PartRecord::new(1, Some(answer.clone()), get_mock_stats());
+        let line = record.to_json_line();
+        assert!(!line.contains('\n'));
+        assert_eq!(
+            PartRecord::try_from(line.as_str()).unwrap().answer,
+            Some(answer)
+        );
+    }
+
+    #[test]
+    fn keeps_nanosecond_precision() {
+        let line = r#"{"part":1,"status":"solved","answer":"0","duration_nanos":74131,"samples":3,"stats":{"samples":3,"mean_nanos":74131,"min_nanos":74000,"median_nanos":74100,"p95_nanos":74293,"std_dev_nanos":12,"outliers":0}}"#;
+        let record = PartRecord::try_from(line).unwrap();
+        assert_eq!(record.stats.mean, Duration::from_nanos(74131));
+        assert_eq!(record.stats.samples, 3);
+    }
+
+    #[test]
+    fn roundtrips_alloc_stats() {
+        let alloc = AllocStats {
+            bytes: 2048,
+            allocations: 3,
+            peak_bytes: 1024,
+        };
+        let record =
+            PartRecord::new(1, Some("42".into()), get_mock_stats()).with_alloc(Some(alloc));
+        let parsed = PartRecord::try_from(record.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed.alloc, Some(alloc));
+    }
+
+    #[test]
+    fn roundtrips_input_names() {
+        let record =
+            PartRecord::new(1, Some("42".into()), get_mock_stats()).with_input(Some("alice"));
+        let parsed = PartRecord::try_from(record.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed.input, Some("alice".into()));
+
+        let record = PartRecord::new(1, Some("42".into()), get_mock_stats());
+        let parsed = PartRecord::try_from(record.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed.input, None);
+    }
+
+    #[test]
+    fn checks_known_answers() {
+        let record = PartRecord::new(1, Some("42".into()), get_mock_stats());
+        assert_eq!(record.is_correct(), None);
+
+        let record = record.with_expected(Some("42".into()));
+        let parsed = PartRecord::try_from(record.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed.is_correct(), Some(true));
+
+        let record = PartRecord::new(1, Some("41".into()), get_mock_stats())
+            .with_expected(Some("42".into()));
+        assert_eq!(record.is_correct(), Some(false));
+
+        let record = PartRecord::new(1, None, get_mock_stats()).with_expected(Some("42".into()));
+        assert_eq!(record.is_correct(), Some(false));
+    }
+
+    #[test]
+    fn roundtrips_diagnostics() {
+        let record = PartRecord::new(2, Some("HEBY".into()), get_mock_stats())
+            .with_diagnostics(Some("#..#\n####".into()));
+        let parsed = PartRecord::try_from(record.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed.diagnostics, Some("#..#\n####".into()));
+        assert_eq!(parsed.answer, Some("HEBY".into()));
+    }
+
+    #[test]
+    fn roundtrips_failed_records() {
+        let error = "error: invalid input at line 3\n    3 | 3a".to_string();
+        let record = PartRecord::failed(1, error.clone(), get_mock_stats());
+        let parsed = PartRecord::try_from(record.to_json_line().as_str()).unwrap();
+        assert_eq!(parsed.status, PartStatus::Failed);
+        assert_eq!(parsed.error, Some(error));
+        assert_eq!(parsed.answer, None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_non_records() {
+        PartRecord::try_from("Part 1: 0 (74.13ns @ 100000 samples)").unwrap();
+    }
+}
@@ -0,0 +1,80 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use crate::template::{Day, Year};
+
+/// Identifies a puzzle by the year of the event and its day.
+///
+/// # Display
+/// This value displays as the year and the two digit day, which is also the name of the solution's binary.
+///
+/// ```
+/// # use advent_of_code::template::{Day, Puzzle, Year};
+/// let puzzle = Puzzle::new(Year::new(2025).unwrap(), Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2025-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Puzzle {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl Puzzle {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Creates a [`Puzzle`] if the event of `year` has a puzzle on `day`, see [`Year::event_days`].
+    pub fn checked(year: Year, day: Day) -> Result<Self, DayOutOfRangeError> {
+        if year.has_day(day) {
+            Ok(Self { year, day })
+        } else {
+            Err(DayOutOfRangeError { year, day })
+        }
+    }
+
+    /// Path of a file of this puzzle in the year's data directory, e.g. `data/2025/inputs/08.txt`.
+    pub fn data_path(&self, folder: &str, extension: &str) -> String {
+        format!("data/{}/{folder}/{}.{extension}", self.year, self.day)
+    }
+
+    /// Path of the solution's source file, e.g. `src/bin/2025-08.rs`.
+    pub fn bin_path(&self) -> String {
+        format!("src/bin/{self}.rs")
+    }
+}
+
+impl Display for Puzzle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/// An error which is returned when a day is not part of the event of a year.
+#[derive(Debug)]
+pub struct DayOutOfRangeError {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl Error for DayOutOfRangeError {}
+
+impl Display for DayOutOfRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (day, year, days) = (self.day.into_inner(), self.year, self.year.event_days());
+        write!(
+            f,
+            "day {day} is not part of Advent of Code {year}, which has {days} puzzles. Pick a day between 1 and {days}, or set `AOC_EVENT_DAYS_{year}` to change the number of puzzles."
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Puzzle`] value in a const context.
+#[macro_export]
+macro_rules! puzzle {
+    ($year:expr, $day:expr) => {
+        $crate::template::Puzzle::new($crate::year!($year), $crate::day!($day))
+    };
+}
@@ -1,9 +1,9 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::io;
 
-use crate::template::Day;
-use crate::template::timings::Timings;
+use crate::template::timings::{PartTiming, Timings};
+use crate::template::{Puzzle, Year};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -21,17 +21,18 @@ impl From<std::io::Error> for Error {
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub pos_start: usize,
+    pub pos_end: usize,
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: Puzzle) -> String {
+    format!("./{}", puzzle.bin_path())
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Locate the table enclosed by two occurences of `marker`.
+pub fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -52,107 +53,136 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+/// Construct the benchmark tables, one per year, newest year first.
+/// Only timings of the default input are listed.
+fn construct_table(prefix: &str, timings: Timings, show_alloc: bool) -> String {
     let header = format!("{prefix} Benchmarks");
+    let timings = Timings {
+        data: timings
+            .data
+            .into_iter()
+            .filter(|timing| timing.input.is_none())
+            .collect(),
+    };
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header];
+
+    let mut years: Vec<Year> = timings.data.iter().map(|timing| timing.year).collect();
+    years.sort_unstable_by(|a, b| b.cmp(a));
+    years.dedup();
+
+    for year in years {
+        let year_timings = Timings {
+            data: timings
+                .data
+                .iter()
+                .filter(|timing| timing.year == year)
+                .cloned()
+                .collect(),
+        };
+
+        lines.extend([
+            String::new(),
+            format!("{prefix}# {year}"),
+            String::new(),
+            "| Day | Parse | Part 1 | Part 2 |".into(),
+            "| :---: | :---: | :---: | :---:  |".into(),
+        ]);
+
+        for timing in &year_timings.data {
+            let path = get_path_for_bin(timing.puzzle());
+            lines.push(format!(
+                "| [Day {}]({}) | {} | {} | {} |",
+                timing.day.into_inner(),
+                path,
+                format_part(timing.parse.as_ref(), show_alloc),
+                format_part(timing.part_1.as_ref(), show_alloc),
+                format_part(timing.part_2.as_ref(), show_alloc)
+            ));
+        }
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
-
-    for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        lines.push(String::new());
+        lines.push(format!("**Total: {:.2}ms**", year_timings.total_millis()));
     }
 
-    lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
     lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
-    Ok(())
+fn format_part(part: Option<&PartTiming>, show_alloc: bool) -> String {
+    let Some(part) = part else {
+        return "`-`".into();
+    };
+
+    let duration = format!("`{:.1?}`", part.duration());
+
+    match part.alloc {
+        Some(alloc) if show_alloc => format!("{duration} <br> {}", alloc.summary()),
+        _ => duration,
+    }
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
-    fs::write(path, &readme)?;
+/// Updates the benchmark table in the readme contents `s`. Each year gets its own table and total.
+/// With `show_alloc`, allocation stats are listed below the duration of each part.
+pub fn update_content(s: &mut String, timings: Timings, show_alloc: bool) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+    let table = construct_table("##", timings, show_alloc);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-#[cfg(feature = "test_lib")]
+#[cfg(all(test, feature = "test_lib"))]
 mod tests {
     use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
-
+    use crate::{
+        day,
+        template::{
+            alloc::AllocStats,
+            timings::{
+                PartTiming, Timing, Timings,
+                fixtures::{self, part, timing},
+            },
+        },
+        year,
+    };
+
+    /// The shared mock timings, with a parse phase and both parts benched on day 4.
     fn get_mock_timings() -> Timings {
-        Timings {
-            data: vec![
-                Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
-                },
-                Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
-                },
-                Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
-                },
-            ],
-        }
+        let mut timings = fixtures::get_mock_timings();
+        timings.data[2] = Timing {
+            parse: part(5),
+            ..timing(day!(4), part(40), part(50))
+        };
+        timings
     }
 
     #[test]
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), false).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), false).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        update_content(&mut s, get_mock_timings(), false).unwrap();
+        assert!(s.contains("## Benchmarks"));
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), false).unwrap();
+        update_content(&mut s, get_mock_timings(), false).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,24 +190,65 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), false).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
-            "| Day | Part 1 | Part 2 |",
-            "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "### 2025",
+            "",
+            "| Day | Parse | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2025-01.rs) | `-` | `10.0ms` | `20.0ms` |",
+            "| [Day 2](./src/bin/2025-02.rs) | `-` | `30.0ms` | `40.0ms` |",
+            "| [Day 4](./src/bin/2025-04.rs) | `5.0ms` | `40.0ms` | `50.0ms` |",
             "",
-            "**Total: 190.00ms**",
+            "**Total: 195.00ms**",
             "<!--- benchmarking table --->",
             "baz",
         ]
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn formats_a_table_per_year() {
+        let mut timings = get_mock_timings();
+        timings.data[2].year = year!(2024);
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, false).unwrap();
+
+        let newer = s.find("### 2025").unwrap();
+        let older = s.find("### 2024").unwrap();
+        assert!(newer < older);
+        assert!(s.contains("| [Day 4](./src/bin/2024-04.rs) |"));
+        assert!(s.contains("**Total: 100.00ms**"));
+        assert!(s.contains("**Total: 95.00ms**"));
+    }
+
+    #[test]
+    fn format_alloc_stats() {
+        let mut timings = get_mock_timings();
+        timings.data[0].part_1 = part(10).map(|part| PartTiming {
+            alloc: Some(AllocStats {
+                bytes: 2048,
+                allocations: 3,
+                peak_bytes: 100,
+            }),
+            ..part
+        });
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings.clone(), true).unwrap();
+        assert!(
+            s.contains("| [Day 1](./src/bin/2025-01.rs) | `-` | `10.0ms` <br> 2.0 KiB in 3 allocs, peak 100 B | `20.0ms` |")
+        );
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, false).unwrap();
+        assert!(!s.contains("allocs"));
+    }
 }
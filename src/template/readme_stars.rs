@@ -0,0 +1,273 @@
+//! Module that updates the readme with the collected stars, in the format of `aoc-readme-stars`.
+//! Stars are derived from local knowledge instead of the website: known answers, correct submissions and solved parts.
+
+use std::{collections::BTreeSet, fs};
+
+use crate::template::answers::Answers;
+use crate::template::readme_benchmarks::{Error, locate_table};
+use crate::template::submissions::{SubmissionLog, SubmissionResult};
+use crate::template::timings::{Timing, Timings};
+use crate::template::{Puzzle, Year};
+
+pub static MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// Stars of part one and two of a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DayStars {
+    pub puzzle: Puzzle,
+    pub parts: [bool; 2],
+}
+
+/// Collect the stars of every year that has timings or data, newest year first.
+/// Days without any star are left out.
+pub fn collect(timings: &Timings) -> Vec<DayStars> {
+    let mut years: BTreeSet<Year> = timings.data.iter().map(|timing| timing.year).collect();
+
+    if let Ok(entries) = fs::read_dir("data") {
+        years.extend(
+            entries
+                .flatten()
+                .filter(|entry| entry.path().is_dir())
+                .filter_map(|entry| entry.file_name().to_str()?.parse::<Year>().ok()),
+        );
+    }
+
+    years
+        .into_iter()
+        .rev()
+        .flat_map(|year| year.days().map(move |day| Puzzle::new(year, day)))
+        .map(|puzzle| {
+            let timing = timings
+                .data
+                .iter()
+                .find(|timing| timing.puzzle() == puzzle && timing.input.is_none());
+
+            DayStars {
+                puzzle,
+                parts: get_stars(
+                    &Answers::read(puzzle, None),
+                    &SubmissionLog::read(puzzle),
+                    timing,
+                ),
+            }
+        })
+        .filter(|stars| stars.parts.iter().any(|part| *part))
+        .collect()
+}
+
+/// A part has a star if its answer is known, it was submitted successfully or the solution returns a result for it.
+fn get_stars(answers: &Answers, log: &SubmissionLog, timing: Option<&Timing>) -> [bool; 2] {
+    [1, 2].map(|part| {
+        let is_known = answers.get(part).is_some();
+
+        let is_submitted = log.entries.iter().any(|entry| {
+            entry.part == part
+                && matches!(
+                    entry.result,
+                    SubmissionResult::Correct | SubmissionResult::AlreadySolved
+                )
+        });
+
+        let is_solved = timing.is_some_and(|timing| match part {
+            1 => timing.part_1.is_some(),
+            _ => timing.part_2.is_some(),
+        });
+
+        is_known || is_submitted || is_solved
+    })
+}
+
+fn construct_table(stars: &[DayStars]) -> String {
+    let mut lines: Vec<String> = vec![MARKER.into()];
+
+    let mut years: Vec<Year> = stars.iter().map(|day| day.puzzle.year).collect();
+    years.dedup();
+
+    for (i, year) in years.into_iter().enumerate() {
+        if i > 0 {
+            lines.push(String::new());
+        }
+
+        lines.extend([
+            format!("## {year} Results"),
+            String::new(),
+            "| Day | Part 1 | Part 2 |".into(),
+            "| :---: | :---: | :---: |".into(),
+        ]);
+
+        for day in stars.iter().filter(|day| day.puzzle.year == year) {
+            let [part_1, part_2] = day.parts.map(|part| if part { "⭐" } else { " " });
+            let number = day.puzzle.day.into_inner();
+            lines.push(format!(
+                "| [Day {number}](https://adventofcode.com/{year}/day/{number}) | {part_1} | {part_2} |"
+            ));
+        }
+    }
+
+    lines.push(MARKER.into());
+    lines.join("\n")
+}
+
+/// Parse the stars of an existing table, e.g. one written by the `aoc-readme-stars` action.
+fn parse_table(table: &str) -> Vec<DayStars> {
+    table
+        .lines()
+        .filter_map(|line| {
+            let url = line.split("](https://adventofcode.com/").nth(1)?;
+            let (url, cells) = url.split_once(')')?;
+
+            let mut segments = url.split('/');
+            let year = segments.next()?.parse().ok()?;
+            let day = segments.nth(1)?.parse().ok()?;
+            let puzzle = Puzzle::checked(year, day).ok()?;
+
+            let mut cells = cells.split('|').skip(1).map(|cell| cell.contains('⭐'));
+            Some(DayStars {
+                puzzle,
+                parts: [cells.next()?, cells.next()?],
+            })
+        })
+        .collect()
+}
+
+/// Updates the stars table in the readme contents `s`. Each year gets its own table.
+/// Stars of the existing table are kept, since earned stars can not be lost and might not be known locally.
+pub fn update_content(s: &mut String, stars: &[DayStars]) -> Result<(), Error> {
+    let positions = locate_table(s, MARKER)?;
+
+    let mut merged = parse_table(&s[positions.pos_start..positions.pos_end]);
+    for day in stars {
+        match merged.iter_mut().find(|known| known.puzzle == day.puzzle) {
+            Some(known) => {
+                known.parts[0] |= day.parts[0];
+                known.parts[1] |= day.parts[1];
+            }
+            None => merged.push(*day),
+        }
+    }
+    merged.sort_by(|a, b| {
+        b.puzzle
+            .year
+            .cmp(&a.puzzle.year)
+            .then(a.puzzle.day.cmp(&b.puzzle.day))
+    });
+
+    let table = construct_table(&merged);
+    s.replace_range(positions.pos_start..positions.pos_end, &table);
+    Ok(())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{DayStars, MARKER, get_stars, update_content};
+    use crate::day;
+    use crate::puzzle;
+    use crate::template::answers::Answers;
+    use crate::template::submissions::{SubmissionEntry, SubmissionLog, SubmissionResult};
+    use crate::template::timings::fixtures::{part, timing};
+
+    fn entry(part: u8, result: SubmissionResult) -> SubmissionEntry {
+        SubmissionEntry {
+            timestamp: 0,
+            part,
+            answer: "42".into(),
+            result,
+        }
+    }
+
+    #[test]
+    fn derives_stars_from_local_knowledge() {
+        let none = Answers::default();
+        let empty = SubmissionLog::default();
+        assert_eq!(get_stars(&none, &empty, None), [false, false]);
+
+        let answers = Answers {
+            part_1: Some("42".into()),
+            part_2: None,
+        };
+        assert_eq!(get_stars(&answers, &empty, None), [true, false]);
+
+        let log = SubmissionLog {
+            entries: vec![
+                entry(1, SubmissionResult::TooHigh),
+                entry(2, SubmissionResult::Correct),
+            ],
+        };
+        assert_eq!(get_stars(&none, &log, None), [false, true]);
+
+        let benched = timing(day!(1), part(1), None);
+        assert_eq!(get_stars(&none, &empty, Some(&benched)), [true, false]);
+    }
+
+    #[test]
+    fn formats_stars() {
+        let stars = [
+            DayStars {
+                puzzle: puzzle!(2025, 1),
+                parts: [true, true],
+            },
+            DayStars {
+                puzzle: puzzle!(2025, 3),
+                parts: [true, false],
+            },
+            DayStars {
+                puzzle: puzzle!(2024, 25),
+                parts: [true, true],
+            },
+        ];
+
+        let mut s = format!("foo\n{MARKER}\n## 2025 Results\n{MARKER}\nbar");
+        update_content(&mut s, &stars).unwrap();
+
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table --->",
+            "## 2025 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |",
+            "| [Day 3](https://adventofcode.com/2025/day/3) | ⭐ |   |",
+            "",
+            "## 2024 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 25](https://adventofcode.com/2024/day/25) | ⭐ | ⭐ |",
+            "<!--- advent_readme_stars table --->",
+            "bar",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn keeps_existing_stars() {
+        let mut s = [
+            MARKER,
+            "## 2025 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |",
+            "| [Day 2](https://adventofcode.com/2025/day/2) | ⭐ |   |",
+            MARKER,
+        ]
+        .join("\n");
+
+        let stars = [DayStars {
+            puzzle: puzzle!(2025, 2),
+            parts: [false, true],
+        }];
+        update_content(&mut s, &stars).unwrap();
+
+        assert!(s.contains("| [Day 1](https://adventofcode.com/2025/day/1) | ⭐ | ⭐ |"));
+        assert!(s.contains("| [Day 2](https://adventofcode.com/2025/day/2) | ⭐ | ⭐ |"));
+
+        let mut unchanged = s.clone();
+        update_content(&mut unchanged, &[]).unwrap();
+        assert_eq!(unchanged, s);
+    }
+}
@@ -1,58 +1,234 @@
-use std::{collections::HashSet, io};
+use std::{collections::HashSet, fmt::Display, io, process};
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RED, ANSI_RESET, Day, Puzzle, Year};
 
 use super::{
-    all_days,
+    protocol::{PARSE_PART, PartRecord, PartStatus, part_label},
     timings::{Timing, Timings},
 };
-
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
+use child_commands::SolutionRun;
+
+/// Runs the solutions for `days_to_run` of `year`. With `jobs > 1`, all binaries are built once
+/// upfront and up to `jobs` days run concurrently. Output stays grouped and in day order.
+/// With `alloc_stats`, the solutions are built with the `alloc-stats` feature.
+/// With `input`, the solutions run against the named input instead of the default one.
+///
+/// Timings are only collected for timed runs. Parts that did not match their known answer, failed parts and
+/// unsolved days are always collected.
+pub fn run_multi(
+    year: Year,
+    days_to_run: &HashSet<Day>,
+    is_release: bool,
+    is_timed: bool,
+    jobs: usize,
+    alloc_stats: bool,
+    input: Option<&str>,
+) -> RunSummary {
     let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    let mut mismatches: Vec<(Puzzle, PartRecord)> = vec![];
+    let mut failures: Vec<(Puzzle, String)> = vec![];
+    let mut unsolved: Vec<Puzzle> = vec![];
 
     let mut need_space = false;
 
     // NOTE: use non-duplicate, sorted day values.
-    all_days()
+    let puzzles: Vec<Puzzle> = year
+        .days()
         .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
+        .map(|day| Puzzle::new(year, day))
+        .collect();
+
+    let mut handle_run = |puzzle: Puzzle, run: Result<SolutionRun, Error>| {
+        let run = match run {
+            Ok(run) => run,
+            Err(e) => {
+                println!("{ANSI_RED}Failed to run: {e}.{ANSI_RESET}");
+                failures.push((puzzle, format!("failed to run: {e}")));
+                return;
             }
-            need_space = true;
+        };
+        let records = &run.records;
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
+        if let Some(crash) = &run.crash {
+            println!("{ANSI_RED}Crashed: {crash}.{ANSI_RESET}");
+            failures.push((puzzle, format!("crashed: {crash}")));
+        } else if records.is_empty() {
+            println!("Not solved.");
+        }
 
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
+        if !records.is_empty() {
+            timings.push(child_commands::timing_from_records(records, puzzle));
+        }
 
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
+        failures.extend(
+            records
+                .iter()
+                .filter(|record| record.status == PartStatus::Failed)
+                .map(|record| (puzzle, describe_failure(record))),
+        );
+
+        let is_solved = records
+            .iter()
+            .any(|record| record.part != PARSE_PART && record.status == PartStatus::Solved);
+        let has_failed = run.crash.is_some()
+            || records
+                .iter()
+                .any(|record| record.status == PartStatus::Failed);
+        if !is_solved && !has_failed {
+            unsolved.push(puzzle);
+        }
+
+        mismatches.extend(
+            records
+                .iter()
+                .filter(|record| {
+                    record.status != PartStatus::Failed && record.is_correct() == Some(false)
+                })
+                .map(|record| (puzzle, record.clone())),
+        );
+    };
+
+    if jobs > 1 {
+        if let Err(e) = child_commands::build_solutions(is_release, alloc_stats) {
+            eprintln!("{ANSI_RED}Failed to build the solutions: {e}.{ANSI_RESET}");
+            process::exit(1);
+        }
+
+        child_commands::run_solutions_parallel(
+            &puzzles,
+            jobs,
+            is_timed,
+            is_release,
+            input,
+            |puzzle, output| {
+                print_day_header(puzzle, &mut need_space);
+                handle_run(puzzle, output.map(|output| output.replay()));
+            },
+        );
+    } else {
+        puzzles.iter().for_each(|&puzzle| {
+            print_day_header(puzzle, &mut need_space);
+            let run =
+                child_commands::run_solution(puzzle, is_timed, is_release, alloc_stats, input);
+            handle_run(puzzle, run);
         });
+    }
 
-    if is_timed {
+    let timings = is_timed.then(|| {
         let timings = Timings { data: timings };
         let total_millis = timings.total_millis();
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
-    } else {
-        None
+        timings
+    });
+
+    RunSummary {
+        timings,
+        mismatches,
+        failures,
+        unsolved,
     }
 }
 
-#[allow(dead_code)]
+/// A failed part in one line, e.g. `Part 1 (alice): invalid input at line 3`.
+fn describe_failure(record: &PartRecord) -> String {
+    let error = record
+        .error
+        .as_deref()
+        .and_then(|error| error.lines().next())
+        .unwrap_or_default();
+
+    format!(
+        "{}{}: {}",
+        part_label(record.part),
+        record
+            .input
+            .as_ref()
+            .map(|input| format!(" ({input})"))
+            .unwrap_or_default(),
+        error.strip_prefix("error: ").unwrap_or(error)
+    )
+}
+
+/// Outcome of [`run_multi`].
+pub struct RunSummary {
+    /// Timings of all solved days, only present for timed runs.
+    pub timings: Option<Timings>,
+    /// Parts whose answer did not match the known answer in `data/{year}/answers`.
+    pub mismatches: Vec<(Puzzle, PartRecord)>,
+    /// Failed parts and crashed solutions, with a description of what went wrong.
+    pub failures: Vec<(Puzzle, String)>,
+    /// Days without a solved or failed part, e.g. because they were not scaffolded or implemented yet.
+    pub unsolved: Vec<Puzzle>,
+}
+
+impl RunSummary {
+    /// Print the parts whose answer did not match the known answer.
+    pub fn print_mismatches(&self) {
+        for (puzzle, record) in &self.mismatches {
+            eprintln!(
+                "{ANSI_RED}Day {}{} {}: expected {}, got {}.{ANSI_RESET}",
+                puzzle.day,
+                record
+                    .input
+                    .as_ref()
+                    .map(|input| format!(" ({input})"))
+                    .unwrap_or_default(),
+                part_label(record.part),
+                record.expected.as_deref().unwrap_or_default(),
+                record.answer.as_deref().unwrap_or("no answer")
+            );
+        }
+    }
+
+    /// Print the failed days, followed by the days that are not solved yet.
+    pub fn print_failures(&self) {
+        if !self.failures.is_empty() {
+            eprintln!("{ANSI_BOLD}Failed:{ANSI_RESET}");
+        }
+        for (puzzle, failure) in &self.failures {
+            eprintln!("{ANSI_RED}  Day {} {failure}{ANSI_RESET}", puzzle.day);
+        }
+
+        if !self.unsolved.is_empty() {
+            let days: Vec<String> = self
+                .unsolved
+                .iter()
+                .map(|puzzle| puzzle.day.to_string())
+                .collect();
+            println!("{ANSI_BOLD}Not solved:{ANSI_RESET} {}", days.join(", "));
+        }
+    }
+}
+
+fn print_day_header(puzzle: Puzzle, need_space: &mut bool) {
+    if *need_space {
+        println!();
+    }
+    *need_space = true;
+
+    println!("{ANSI_BOLD}Day {}{ANSI_RESET}", puzzle.day);
+    println!("------");
+}
+
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
+    BuildFailed,
     IO(io::Error),
 }
 
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "could not capture the output of the solution"),
+            Error::BuildFailed => write!(f, "cargo build failed"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(e: std::io::Error) -> Self {
         Error::IO(e)
@@ -60,44 +236,88 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: Puzzle) -> String {
+    format!("./{}", puzzle.bin_path())
 }
 
 /// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// This module encapsulates interaction with these binaries, both invoking them as well as consuming their `--format json` records.
 pub mod child_commands {
     use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use crate::template::{
+        Puzzle,
+        protocol::{PARSE_PART, PartRecord, PartStatus, part_label},
+        runner::{
+            format_alloc, format_check, format_duration, print_diagnostics, print_failure,
+            print_parse, print_result,
+        },
+        timings::{PartTiming, Timing},
+    };
     use std::{
+        collections::BTreeMap,
+        env,
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        path::{Path, PathBuf},
+        process::{Command, ExitStatus, Stdio},
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            mpsc,
+        },
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Part records of a solution bin.
+    #[derive(Debug, Default)]
+    pub struct SolutionRun {
+        pub records: Vec<PartRecord>,
+        /// Set if the bin did not exit successfully, e.g. because it panicked.
+        pub crash: Option<String>,
+    }
+
+    fn get_crash(status: Option<ExitStatus>) -> Option<String> {
+        status
+            .filter(|status| !status.success())
+            .map(|status| status.to_string())
+    }
+
+    /// Run the solution bin for a given puzzle
+    pub fn run_solution(
+        puzzle: Puzzle,
+        is_timed: bool,
+        is_release: bool,
+        alloc_stats: bool,
+        input: Option<&str>,
+    ) -> Result<SolutionRun, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+        if !Path::new(&get_path_for_bin(puzzle)).exists() {
+            return Ok(SolutionRun::default());
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = puzzle.to_string();
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
 
         if is_release {
             args.push("--release");
         }
 
+        if alloc_stats {
+            args.extend(["--features", "alloc-stats"]);
+        }
+
+        // request machine-readable records from the child instead of its pretty output.
+        args.extend(["--", "--format", "json"]);
+
         if is_timed {
             // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
+        if let Some(input) = input {
+            args.extend(["--input", input]);
+        }
+
         // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        // forward stderr and any non-record stdout lines while grabbing records.
 
         let mut cmd = Command::new("cargo")
             .args(&args)
@@ -108,150 +328,349 @@ pub mod child_commands {
         let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
         let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
 
-        let mut output = vec![];
+        let mut records = vec![];
 
         let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
+            stderr.lines().map_while(Result::ok).for_each(|line| {
+                eprintln!("{line}");
             });
         });
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        let read_result = stdout
+            .lines()
+            .try_for_each(|line| line.map(|line| handle_stdout_line(&line, &mut records)));
+
+        // NOTE: wait for the child before reporting a failed read, so it does not outlive the run.
+        let status = cmd.wait()?;
+        thread.join().map_err(|_| super::Error::BrokenPipe)?;
+        read_result?;
+
+        Ok(SolutionRun {
+            records,
+            crash: get_crash(Some(status)),
+        })
+    }
+
+    /// Profile and features the solution bins are built with, e.g. `release+alloc-stats`.
+    pub fn build_profile(is_release: bool, alloc_stats: bool) -> String {
+        let profile = if is_release { "release" } else { "debug" };
+        if alloc_stats {
+            format!("{profile}+alloc-stats")
+        } else {
+            profile.into()
+        }
+    }
+
+    /// Build all solution bins in one cargo invocation, so they can be invoked directly afterwards.
+    pub fn build_solutions(is_release: bool, alloc_stats: bool) -> Result<(), Error> {
+        let mut args = vec!["build", "--quiet", "--bins"];
+
+        if is_release {
+            args.push("--release");
+        }
+
+        if alloc_stats {
+            args.extend(["--features", "alloc-stats"]);
+        }
+
+        let status = Command::new("cargo").args(&args).status()?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(Error::BuildFailed)
         }
+    }
+
+    /// Output of a solution binary, captured so it can be printed in day order later.
+    #[derive(Debug, Default)]
+    pub struct CapturedOutput {
+        stdout: String,
+        stderr: String,
+        /// `None` if the bin was not run.
+        status: Option<ExitStatus>,
+    }
 
-        thread.join().unwrap();
-        cmd.wait()?;
+    impl CapturedOutput {
+        /// Forward the captured output to stdout/stderr and return the part records it contained.
+        pub fn replay(&self) -> SolutionRun {
+            let mut records = vec![];
 
-        Ok(output)
+            for line in self.stdout.lines() {
+                handle_stdout_line(line, &mut records);
+            }
+
+            self.stderr.lines().for_each(|line| eprintln!("{line}"));
+
+            SolutionRun {
+                records,
+                crash: get_crash(self.status),
+            }
+        }
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+    /// Run the pre-built solution bins for `puzzles` on up to `jobs` threads.
+    /// `on_output` is called on the current thread, in the order of `puzzles`.
+    pub fn run_solutions_parallel(
+        puzzles: &[Puzzle],
+        jobs: usize,
+        is_timed: bool,
+        is_release: bool,
+        input: Option<&str>,
+        mut on_output: impl FnMut(Puzzle, Result<CapturedOutput, Error>),
+    ) {
+        let next_index = AtomicUsize::new(0);
+        let (tx, rx) = mpsc::channel();
+
+        thread::scope(|scope| {
+            for _ in 0..jobs.min(puzzles.len()) {
+                let tx = tx.clone();
+                let next_index = &next_index;
+
+                scope.spawn(move || {
+                    loop {
+                        let index = next_index.fetch_add(1, Ordering::Relaxed);
+                        let Some(&puzzle) = puzzles.get(index) else {
+                            break;
+                        };
+                        let output = run_binary(puzzle, is_timed, is_release, input);
+                        if tx.send((index, output)).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
-                    return None;
-                }
+            drop(tx);
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
-
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+            // days finish in arbitrary order, hold back output until all previous days were printed.
+            let mut pending = BTreeMap::new();
+            let mut next_to_print = 0;
 
-                timings.total_nanos += nanos;
-            });
+            for (index, output) in rx {
+                pending.insert(index, output);
+                while let Some(output) = pending.remove(&next_to_print) {
+                    on_output(puzzles[next_to_print], output);
+                    next_to_print += 1;
+                }
+            }
+        });
+    }
 
-        timings
+    fn get_path_for_binary(puzzle: Puzzle, is_release: bool) -> PathBuf {
+        let target_dir = env::var("CARGO_TARGET_DIR").unwrap_or_else(|_| "target".into());
+        let profile = if is_release { "release" } else { "debug" };
+        Path::new(&target_dir)
+            .join(profile)
+            .join(format!("{puzzle}{}", env::consts::EXE_SUFFIX))
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Invoke a pre-built solution bin directly, bypassing cargo.
+    fn run_binary(
+        puzzle: Puzzle,
+        is_timed: bool,
+        is_release: bool,
+        input: Option<&str>,
+    ) -> Result<CapturedOutput, Error> {
+        // skip command invocation for days that have not been scaffolded yet.
+        if !Path::new(&get_path_for_bin(puzzle)).exists() {
+            return Ok(CapturedOutput::default());
+        }
+
+        let mut args = vec!["--format", "json"];
+
+        if is_timed {
+            args.push("--time");
+        }
+
+        if let Some(input) = input {
+            args.extend(["--input", input]);
+        }
+
+        let output = Command::new(get_path_for_binary(puzzle, is_release))
+            .args(&args)
+            .output()?;
+
+        Ok(CapturedOutput {
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+            status: Some(output.status),
+        })
     }
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+    fn handle_stdout_line(line: &str, records: &mut Vec<PartRecord>) {
+        match PartRecord::try_from(line) {
+            Ok(record) => {
+                print_record(&record);
+                records.push(record);
+            }
+            Err(_) => println!("{line}"),
+        }
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
+    fn print_record(record: &PartRecord) {
+        let duration_str = format!(
+            "{}{}",
+            format_duration(&record.stats),
+            format_alloc(record.alloc.as_ref())
+        );
+
+        if record.part == PARSE_PART {
+            print_parse(&duration_str);
+        } else if record.status == PartStatus::Failed {
+            print_failure(
+                record.error.as_deref().unwrap_or_default(),
+                &part_label(record.part),
+                &duration_str,
             );
-        }};
+        } else {
+            let check = format_check(record.answer.as_deref(), record.expected.as_deref());
+            print_result(
+                &record.answer,
+                &part_label(record.part),
+                &format!("{check}{duration_str}"),
+            );
+            print_diagnostics(record.diagnostics.as_deref());
+        }
+    }
+
+    pub fn timing_from_records(records: &[PartRecord], puzzle: Puzzle) -> Timing {
+        let mut timing = Timing {
+            year: puzzle.year,
+            day: puzzle.day,
+            input: records.iter().find_map(|record| record.input.clone()),
+            parse: None,
+            part_1: None,
+            part_2: None,
+        };
+
+        records
+            .iter()
+            .filter(|record| record.status == PartStatus::Solved)
+            .for_each(|record| {
+                let part = Some(PartTiming {
+                    alloc: record.alloc,
+                    ..PartTiming::from(record.stats)
+                });
+
+                if record.part == PARSE_PART {
+                    timing.parse = part;
+                } else if record.part == 1 {
+                    timing.part_1 = part;
+                } else if record.part == 2 {
+                    timing.part_2 = part;
+                }
+            });
+
+        timing
     }
 
-    #[cfg(feature = "test_lib")]
+    #[cfg(all(test, feature = "test_lib"))]
     mod tests {
-        use super::parse_exec_time;
+        use super::{PARSE_PART, PartStatus, timing_from_records};
+        use std::time::Duration;
+
+        use crate::puzzle;
+        use crate::template::{alloc::AllocStats, protocol::PartRecord, stats::BenchStats};
+
+        fn record(part: u8, answer: Option<&str>, nanos: u64, samples: usize) -> PartRecord {
+            let mut timers = vec![Duration::from_nanos(nanos); samples];
+            PartRecord::new(
+                part,
+                answer.map(Into::into),
+                BenchStats::from_samples(&mut timers),
+            )
+        }
+
+        #[test]
+        fn collects_execution_times() {
+            let res = timing_from_records(
+                &[
+                    record(1, Some("0"), 74, 100_000),
+                    record(2, Some("10"), 74_130_000, 99_999),
+                ],
+                puzzle!(2025, 1),
+            );
+            assert_eq!(res.total_nanos(), 74_130_074);
+            assert_eq!(res.part_1.unwrap().nanos, 74);
+            assert_eq!(res.part_2.unwrap().nanos, 74_130_000);
+            assert_eq!(res.part_1.unwrap().samples, 100_000);
+            assert_eq!(res.part_2.unwrap().samples, 99_999);
+            assert_eq!(res.part_2.unwrap().stats.unwrap().samples, 99_999);
+            assert_eq!(res.part_1.unwrap().alloc, None);
+        }
 
-        use crate::day;
+        #[test]
+        fn collects_alloc_stats() {
+            let alloc = AllocStats {
+                bytes: 100,
+                allocations: 2,
+                peak_bytes: 50,
+            };
+            let res = timing_from_records(
+                &[record(1, Some("0"), 74, 10).with_alloc(Some(alloc))],
+                puzzle!(2025, 1),
+            );
+            assert_eq!(res.part_1.unwrap().alloc, Some(alloc));
+        }
 
         #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
+        fn collects_input_name() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
+                    record(1, Some("0"), 74, 10).with_input(Some("alice")),
+                    record(2, Some("0"), 74, 10).with_input(Some("alice")),
                 ],
-                day!(1),
+                puzzle!(2025, 1),
             );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_eq!(res.input, Some("alice".into()));
+
+            let res = timing_from_records(&[record(1, Some("0"), 74, 10)], puzzle!(2025, 1));
+            assert_eq!(res.input, None);
         }
 
         #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
+        fn collects_with_patterns_in_answer() {
+            let res = timing_from_records(
                 &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
+                    record(1, Some("@ @ @ ( ) ms (2s @ 5 samples)"), 2_000_000_000, 5),
+                    record(2, Some("10s\n(100ms @ 1 samples)"), 100_000_000, 1),
                 ],
-                day!(1),
+                puzzle!(2025, 1),
+            );
+            assert_eq!(res.total_nanos(), 2_100_000_000);
+            assert_eq!(res.part_1.unwrap().nanos, 2_000_000_000);
+            assert_eq!(res.part_2.unwrap().nanos, 100_000_000);
+        }
+
+        #[test]
+        fn skips_unsolved_parts() {
+            let res = timing_from_records(
+                &[record(1, None, 100, 10), record(2, None, 100, 10)],
+                puzzle!(2025, 1),
             );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
+            assert_eq!(res.total_nanos(), 0);
+            assert!(res.part_1.is_none());
+            assert!(res.part_2.is_none());
         }
 
         #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
+        fn collects_parse_times() {
+            let mut parse = record(1, None, 500, 10);
+            parse.part = PARSE_PART;
+            parse.status = PartStatus::Solved;
+
+            let res = timing_from_records(
                 &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
+                    parse,
+                    record(1, Some("1"), 100, 10),
+                    record(2, None, 100, 10),
                 ],
-                day!(1),
+                puzzle!(2025, 1),
             );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert_eq!(res.parse.unwrap().nanos, 500);
+            assert_eq!(res.total_nanos(), 600);
+            assert!(res.part_2.is_none());
         }
     }
 }
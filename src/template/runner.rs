@@ -2,63 +2,179 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{Write, stdout};
-use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
-use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::alloc::{self, AllocStats};
+use crate::template::answers::Answers;
+use crate::template::date::now_timestamp;
+use crate::template::outcome::{Outcome, PartOutput};
+use crate::template::protocol::{PARSE_PART, PartRecord, PartStatus, is_json_output};
+use crate::template::stats::BenchStats;
+use crate::template::submissions::{SubmissionEntry, SubmissionLog, SubmissionResult};
+use crate::template::{ANSI_BOLD, ANSI_GREEN, ANSI_RED};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Puzzle, aoc_client, inputs};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Call `func` with each input selected by the command-line arguments, see [`inputs::from_args`].
+/// If results need to be told apart, a header with the input's name is printed before each.
+pub fn for_each_input(puzzle: Puzzle, func: impl Fn(&str, Option<&str>)) {
+    let inputs = inputs::from_args(puzzle);
+    let is_labelled = inputs.len() > 1 || inputs.iter().any(|input| input.name.is_some());
+
+    for (index, input) in inputs.iter().enumerate() {
+        if is_labelled && !is_json_output() {
+            if index > 0 {
+                println!();
+            }
+            println!("{ANSI_BOLD}Input: {}{ANSI_RESET}", input.label());
+        }
+
+        func(&input.contents, input.name.as_deref());
+    }
+}
+
+/// Run a solution part and report its outcome, see [`PartOutput`]. Diagnostics of the answer are printed below it.
+pub fn run_part<I: Copy, R: PartOutput>(
+    func: impl Fn(I) -> R,
+    input: I,
+    puzzle: Puzzle,
+    part: u8,
+    input_name: Option<&str>,
+) {
     let part_str = format!("Part {part}");
+    let is_json = is_json_output();
+
+    let (outcome, stats, alloc) = run_timed(func, input, |result| {
+        let outcome = result.into_outcome();
+        if !is_json {
+            print_outcome(&outcome, &part_str, "");
+        }
+        outcome
+    });
+
+    let expected = Answers::read(puzzle, input_name)
+        .get(part)
+        .map(ToString::to_string);
+
+    if is_json {
+        let record = match &outcome {
+            Outcome::Solved {
+                answer,
+                diagnostics,
+            } => PartRecord::new(part, Some(answer.clone()), stats)
+                .with_diagnostics(diagnostics.clone()),
+            Outcome::NotImplemented => PartRecord::new(part, None, stats),
+            Outcome::Failed(error) => PartRecord::failed(part, error.clone(), stats),
+        };
+        let record = record
+            .with_alloc(alloc)
+            .with_input(input_name)
+            .with_expected(expected);
+        println!("{}", record.to_json_line());
+    } else {
+        let check = match &outcome {
+            Outcome::Solved { answer, .. } => format_check(Some(answer), expected.as_deref()),
+            _ => String::new(),
+        };
+        let duration_str = format!(
+            "{check}{}{}",
+            format_duration(&stats),
+            format_alloc(alloc.as_ref())
+        );
+        print_outcome(&outcome, &part_str, &duration_str);
+    }
+
+    if let Outcome::Solved { answer, .. } = outcome {
+        submit_result(&answer, puzzle, part);
+    }
+}
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+/// Run the parse function shared by both parts of a solution. It is timed like a part and reported as `Parse`.
+pub fn run_parse<I: Copy, T>(func: impl Fn(I) -> T, input: I, input_name: Option<&str>) -> T {
+    let is_json = is_json_output();
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let (result, stats, alloc) = run_timed(func, input, |result| {
+        if !is_json {
+            print!("Parse:");
+        }
+        result
+    });
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    if is_json {
+        let record = PartRecord {
+            part: PARSE_PART,
+            status: PartStatus::Solved,
+            answer: None,
+            stats,
+            alloc,
+            input: input_name.map(Into::into),
+            expected: None,
+            diagnostics: None,
+            error: None,
+        };
+        println!("{}", record.to_json_line());
+    } else {
+        print_parse(&format!(
+            "{}{}",
+            format_duration(&stats),
+            format_alloc(alloc.as_ref())
+        ));
     }
+
+    result
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
 ///  1. in debug, the function is executed once.
 ///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
-fn run_timed<I: Copy, T>(
+///     A warm-up phase precedes the measurement and is discarded.
+///
+/// With the `alloc-stats` feature, allocations of the first execution are counted as well.
+/// The result of the first execution is passed to `hook` before benching, its return value is returned.
+fn run_timed<I: Copy, T, U>(
     func: impl Fn(I) -> T,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    hook: impl FnOnce(T) -> U,
+) -> (U, BenchStats, Option<AllocStats>) {
     let timer = Instant::now();
-    let result = {
+    let (result, alloc) = {
         #[cfg(feature = "dhat-heap")]
         let _profiler = dhat::Profiler::new_heap();
 
-        func(input)
+        if alloc::is_enabled() {
+            let (result, alloc) = alloc::measure(|| func(input));
+            (result, Some(alloc))
+        } else {
+            (func(input), None)
+        }
     };
     let base_time = timer.elapsed();
 
-    hook(&result);
+    let result = hook(result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
+    let stats = if std::env::args().any(|x| x == "--time") {
         bench(func, input, &base_time)
     } else {
-        (base_time, 1)
+        BenchStats::from_samples(&mut [base_time])
     };
 
-    (result, run.0, run.1)
+    (result, stats, alloc)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
-
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
+fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> BenchStats {
+    if !is_json_output() {
+        print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+    }
 
     let bench_iterations =
         (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
+    // warm up caches and branch predictors, these runs are not measured.
+    for _ in 0..(bench_iterations / 10).max(1) {
+        black_box(func(black_box(input)));
+    }
+
     let mut timers: Vec<Duration> = vec![];
 
     for _ in 0..bench_iterations {
@@ -67,30 +183,43 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
         timers.push(timer.elapsed());
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    BenchStats::from_samples(&mut timers)
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
+pub(crate) fn format_duration(stats: &BenchStats) -> String {
+    let BenchStats {
+        samples,
+        mean,
+        min,
+        median,
+        p95,
+        std_dev,
+        outliers,
+    } = stats;
 
-fn format_duration(duration: &Duration, samples: u128) -> String {
-    if samples == 1 {
-        format!(" ({duration:.1?})")
+    if *samples == 1 {
+        format!(" ({mean:.1?})")
     } else {
-        format!(" ({duration:.1?} @ {samples} samples)")
+        format!(
+            " ({mean:.1?} @ {samples} samples) [min {min:.1?}, median {median:.1?}, p95 {p95:.1?}, σ {std_dev:.1?}, {outliers} outliers]"
+        )
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+/// Marks an answer as correct or wrong if the known answer is present, see [`Answers`].
+pub(crate) fn format_check(answer: Option<&str>, expected: Option<&str>) -> String {
+    match expected {
+        None => String::new(),
+        Some(expected) if answer == Some(expected) => format!(" {ANSI_GREEN}✔{ANSI_RESET}"),
+        Some(expected) => format!(" {ANSI_RED}✘ expected {expected}{ANSI_RESET}"),
+    }
+}
+
+pub(crate) fn format_alloc(alloc: Option<&AllocStats>) -> String {
+    alloc.map_or_else(String::new, |alloc| format!(" | {}", alloc.summary()))
+}
+
+pub(crate) fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
@@ -125,18 +254,60 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
     }
 }
 
+/// Print the outcome of a part, see [`print_result`]. Failures are followed by their error chain.
+fn print_outcome(outcome: &Outcome, part: &str, duration_str: &str) {
+    match outcome {
+        Outcome::Solved {
+            answer,
+            diagnostics,
+        } => {
+            print_result(&Some(answer), part, duration_str);
+            if !duration_str.is_empty() {
+                print_diagnostics(diagnostics.as_deref());
+            }
+        }
+        Outcome::NotImplemented => print_result(&None::<&str>, part, duration_str),
+        Outcome::Failed(error) => print_failure(error, part, duration_str),
+    }
+}
+
+/// Print a failed part and its error chain in red. Without `duration_str`, the line is overwritten later.
+pub(crate) fn print_failure(error: &str, part: &str, duration_str: &str) {
+    if duration_str.is_empty() {
+        print!("{part}: {ANSI_RED}✘ failed{ANSI_RESET}");
+        return;
+    }
+
+    print!("\r");
+    println!("{part}: {ANSI_RED}✘ failed{ANSI_RESET}{duration_str}");
+    for line in error.lines() {
+        println!("  {ANSI_RED}{line}{ANSI_RESET}");
+    }
+}
+
+/// Print the diagnostics of a part below its result, indented and in italics.
+pub(crate) fn print_diagnostics(diagnostics: Option<&str>) {
+    for line in diagnostics.into_iter().flat_map(str::lines) {
+        println!("  {ANSI_ITALIC}{line}{ANSI_RESET}");
+    }
+}
+
+pub(crate) fn print_parse(duration_str: &str) {
+    print!("\r");
+    println!("Parse:{duration_str}");
+}
+
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. the selected backend is usable, see [`aoc_client::backend`].
+///
+/// Answers that were already rejected are not submitted again. Every attempt is logged, see [`SubmissionLog`],
+/// and accepted answers are stored as known answers, see [`Answers`].
+fn submit_result(result: &str, puzzle: Puzzle, part: u8) {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
-        return None;
+        return;
     }
 
     if args.len() < 3 {
@@ -152,16 +323,82 @@ fn submit_result<T: Display>(
     };
 
     if part_submit != part {
-        return None;
+        return;
     }
 
-    if aoc_cli::check().is_err() {
+    if result.contains('\n') {
         eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
+            "{ANSI_RED}Not submitting part {part}: multi-line answers can not be submitted. If the answer is drawn in letters, return it as `AsciiArt`.{ANSI_RESET}"
         );
+        return;
+    }
+
+    let log = SubmissionLog::read(puzzle);
+
+    if let Some(entry) = log.find_wrong(part, result) {
+        eprintln!(
+            "{ANSI_RED}Not submitting {result}: it was already submitted for part {part} and was {}.{ANSI_RESET}",
+            entry.result
+        );
+        return;
+    }
+
+    if let Some(wait) = log.remaining_wait(now_timestamp()) {
+        eprintln!(
+            "{ANSI_RED}Not submitting {result}: the last submission for day {} was rate-limited, wait {}s before trying again.{ANSI_RESET}",
+            puzzle.day,
+            wait.as_secs()
+        );
+        return;
+    }
+
+    if let Some(warning) = log.check_bounds(part, result) {
+        eprintln!("{ANSI_RED}Warning: {warning}{ANSI_RESET}");
+    }
+
+    let backend = aoc_client::backend();
+
+    if let Err(e) = backend.check() {
+        eprintln!("{e}");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    println!("Submitting result...");
+    let response = match backend.submit(puzzle, part, result) {
+        Ok(response) => response,
+        Err(e) => {
+            eprintln!("{ANSI_RED}Failed to submit part {part}: {e}{ANSI_RESET}");
+            process::exit(1);
+        }
+    };
+    println!("{}", response.trim());
+
+    let submission = SubmissionResult::from_response(&response);
+    let entry = SubmissionEntry {
+        timestamp: now_timestamp(),
+        part,
+        answer: result.into(),
+        result: submission,
+    };
+
+    if let Err(e) = SubmissionLog::append(puzzle, &entry) {
+        eprintln!("Failed to log submission: {e}");
+    }
+
+    let color = match submission {
+        SubmissionResult::Correct => ANSI_GREEN,
+        SubmissionResult::AlreadySolved | SubmissionResult::Unknown => ANSI_RESET,
+        _ => ANSI_RED,
+    };
+    println!("{ANSI_BOLD}Result:{ANSI_RESET} {color}{submission}{ANSI_RESET}");
+
+    if submission == SubmissionResult::Correct {
+        match Answers::store(puzzle, None, part, result) {
+            Ok(()) => println!(
+                "🎄 Stored answer to part {part} in \"data/{}/answers\".",
+                puzzle.year
+            ),
+            Err(e) => eprintln!("Failed to store answer: {e}"),
+        }
+    }
 }
@@ -0,0 +1,127 @@
+//! Summary statistics for the samples collected while benching a solution part.
+
+use std::time::Duration;
+
+/// Distribution of a set of benchmark samples.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BenchStats {
+    pub samples: u128,
+    pub mean: Duration,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+    /// Number of samples outside of the Tukey fences (1.5 × IQR below Q1 / above Q3).
+    pub outliers: u128,
+}
+
+impl BenchStats {
+    /// Computes the distribution of `samples`. The slice is sorted in place.
+    ///
+    /// # Panics
+    /// Panics if `samples` is empty.
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "expected at least one sample.");
+
+        samples.sort_unstable();
+
+        let nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        let len = nanos.len() as f64;
+
+        let mean = nanos.iter().sum::<f64>() / len;
+
+        let std_dev = if nanos.len() > 1 {
+            let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (len - 1.0);
+            variance.sqrt()
+        } else {
+            0.0
+        };
+
+        let median = if nanos.len().is_multiple_of(2) {
+            let mid = nanos.len() / 2;
+            (nanos[mid - 1] + nanos[mid]) / 2.0
+        } else {
+            nanos[nanos.len() / 2]
+        };
+
+        let q1 = percentile(&nanos, 0.25);
+        let q3 = percentile(&nanos, 0.75);
+        let iqr = q3 - q1;
+        let (lower_fence, upper_fence) = (q1 - 1.5 * iqr, q3 + 1.5 * iqr);
+
+        let outliers = nanos
+            .iter()
+            .filter(|&&x| x < lower_fence || x > upper_fence)
+            .count();
+
+        Self {
+            samples: samples.len() as u128,
+            mean: nanos_to_duration(mean),
+            min: samples[0],
+            median: nanos_to_duration(median),
+            p95: nanos_to_duration(percentile(&nanos, 0.95)),
+            std_dev: nanos_to_duration(std_dev),
+            outliers: outliers as u128,
+        }
+    }
+}
+
+/// Nearest-rank percentile of a sorted, non-empty slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn nanos_to_duration(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::BenchStats;
+    use std::time::Duration;
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&x| Duration::from_nanos(x)).collect()
+    }
+
+    #[test]
+    fn handles_single_sample() {
+        let stats = BenchStats::from_samples(&mut nanos(&[42]));
+        assert_eq!(stats.samples, 1);
+        assert_eq!(stats.mean, Duration::from_nanos(42));
+        assert_eq!(stats.min, Duration::from_nanos(42));
+        assert_eq!(stats.median, Duration::from_nanos(42));
+        assert_eq!(stats.p95, Duration::from_nanos(42));
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn computes_distribution() {
+        let mut samples = nanos(&[50, 10, 40, 20, 30]);
+        let stats = BenchStats::from_samples(&mut samples);
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.mean, Duration::from_nanos(30));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.median, Duration::from_nanos(30));
+        assert_eq!(stats.p95, Duration::from_nanos(50));
+        // sample standard deviation of 10..=50 in steps of 10 is sqrt(250).
+        assert_eq!(stats.std_dev, Duration::from_nanos(16));
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn averages_median_of_even_samples() {
+        let stats = BenchStats::from_samples(&mut nanos(&[10, 20, 30, 40]));
+        assert_eq!(stats.median, Duration::from_nanos(25));
+    }
+
+    #[test]
+    fn counts_outliers() {
+        let mut samples = nanos(&[10, 11, 10, 12, 11, 10, 11, 12, 10, 500]);
+        let stats = BenchStats::from_samples(&mut samples);
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.min, Duration::from_nanos(10));
+    }
+}
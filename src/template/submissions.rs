@@ -0,0 +1,491 @@
+//! Outcome of answer submissions and the per-day log of all attempts in `data/{year}/submissions/{day}.jsonl`.
+//! The log is used to refuse answers that are known to be wrong, and submissions while rate-limited, before sending them.
+
+use std::{
+    collections::HashMap,
+    env,
+    fmt::Display,
+    fs::{self, OpenOptions},
+    io::{Error, Write},
+    path::PathBuf,
+    str::FromStr,
+    time::Duration,
+};
+use tinyjson::JsonValue;
+
+use crate::template::Puzzle;
+
+/// Result of submitting an answer, parsed from the response message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubmissionResult {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    AlreadySolved,
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The response could not be interpreted.
+    Unknown,
+}
+
+impl SubmissionResult {
+    pub fn from_response(response: &str) -> Self {
+        if response.contains("That's the right answer") {
+            SubmissionResult::Correct
+        } else if response.contains("your answer is too high") {
+            SubmissionResult::TooHigh
+        } else if response.contains("your answer is too low") {
+            SubmissionResult::TooLow
+        } else if response.contains("That's not the right answer") {
+            SubmissionResult::Wrong
+        } else if response.contains("Did you already complete it") {
+            SubmissionResult::AlreadySolved
+        } else if response.contains("You gave an answer too recently") {
+            SubmissionResult::RateLimited {
+                wait: parse_wait_time(response),
+            }
+        } else {
+            SubmissionResult::Unknown
+        }
+    }
+
+    /// Returns `true` if the submitted answer is known to be wrong.
+    pub fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            SubmissionResult::TooHigh | SubmissionResult::TooLow | SubmissionResult::Wrong
+        )
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            SubmissionResult::Correct => "correct",
+            SubmissionResult::TooHigh => "too_high",
+            SubmissionResult::TooLow => "too_low",
+            SubmissionResult::Wrong => "wrong",
+            SubmissionResult::AlreadySolved => "already_solved",
+            SubmissionResult::RateLimited { .. } => "rate_limited",
+            SubmissionResult::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for SubmissionResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmissionResult::Correct => write!(f, "correct"),
+            SubmissionResult::TooHigh => write!(f, "wrong, too high"),
+            SubmissionResult::TooLow => write!(f, "wrong, too low"),
+            SubmissionResult::Wrong => write!(f, "wrong"),
+            SubmissionResult::AlreadySolved => write!(f, "already solved"),
+            SubmissionResult::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate-limited, wait {}s", wait.as_secs())
+            }
+            SubmissionResult::RateLimited { wait: None } => write!(f, "rate-limited"),
+            SubmissionResult::Unknown => write!(f, "unknown response"),
+        }
+    }
+}
+
+/// Parses the wait time of a rate-limit response, e.g. `You have 1m 30s left to wait.`
+fn parse_wait_time(response: &str) -> Option<Duration> {
+    let start = response.find("You have ")? + "You have ".len();
+    let end = start + response[start..].find(" left to wait")?;
+
+    response[start..end]
+        .split_whitespace()
+        .map(|part| {
+            let unit_start = part.find(|c: char| !c.is_ascii_digit())?;
+            let value: u64 = part[..unit_start].parse().ok()?;
+            match &part[unit_start..] {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A single submission attempt.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubmissionEntry {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub part: u8,
+    pub answer: String,
+    pub result: SubmissionResult,
+}
+
+/// All submission attempts of a day, oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct SubmissionLog {
+    pub entries: Vec<SubmissionEntry>,
+}
+
+impl SubmissionLog {
+    /// Read the log of `puzzle`. If not present, returns an empty log.
+    /// Lines that can not be parsed, e.g. after an interrupted write, are skipped with a warning.
+    pub fn read(puzzle: Puzzle) -> Self {
+        let path = get_log_path(puzzle);
+        let Ok(contents) = fs::read_to_string(&path) else {
+            return SubmissionLog::default();
+        };
+
+        let (log, errors) = SubmissionLog::parse(&contents);
+        for (line, error) in errors {
+            eprintln!(
+                "Warning: skipped line {line} of \"{}\": {error}",
+                path.display()
+            );
+        }
+        log
+    }
+
+    /// Append an attempt to the log of `puzzle`.
+    pub fn append(puzzle: Puzzle, entry: &SubmissionEntry) -> Result<(), Error> {
+        let path = get_log_path(puzzle);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        // NOTE: stringifying a JSON value built from strings and numbers can not fail.
+        writeln!(file, "{}", JsonValue::from(entry).stringify().unwrap())
+    }
+
+    /// An earlier submission of `answer` for `part` that was wrong.
+    pub fn find_wrong(&self, part: u8, answer: &str) -> Option<&SubmissionEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.part == part && entry.answer == answer && entry.result.is_wrong())
+    }
+
+    /// Time left until the rate limit of the latest submission ends, `None` if it is over or was not rate-limited.
+    /// `now` is in seconds since the unix epoch.
+    pub fn remaining_wait(&self, now: u64) -> Option<Duration> {
+        let entry = self.entries.last()?;
+        let SubmissionResult::RateLimited { wait: Some(wait) } = entry.result else {
+            return None;
+        };
+
+        let end = entry.timestamp.saturating_add(wait.as_secs());
+        (end > now).then(|| Duration::from_secs(end - now))
+    }
+
+    /// Warns if a numeric `answer` for `part` contradicts an earlier "too high" or "too low" result.
+    pub fn check_bounds(&self, part: u8, answer: &str) -> Option<String> {
+        let answer: i128 = answer.trim().parse().ok()?;
+
+        let bound = |result: SubmissionResult| {
+            self.entries
+                .iter()
+                .filter(move |entry| entry.part == part && entry.result == result)
+                .filter_map(|entry| entry.answer.trim().parse::<i128>().ok())
+        };
+
+        if let Some(upper) = bound(SubmissionResult::TooHigh)
+            .min()
+            .filter(|x| answer >= *x)
+        {
+            return Some(format!(
+                "{answer} is not lower than {upper}, which was too high."
+            ));
+        }
+
+        if let Some(lower) = bound(SubmissionResult::TooLow)
+            .max()
+            .filter(|x| answer <= *x)
+        {
+            return Some(format!(
+                "{answer} is not higher than {lower}, which was too low."
+            ));
+        }
+
+        None
+    }
+}
+
+fn get_log_path(puzzle: Puzzle) -> PathBuf {
+    env::current_dir()
+        .unwrap()
+        .join(puzzle.data_path("submissions", "jsonl"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for SubmissionResult {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(SubmissionResult::Correct),
+            "too_high" => Ok(SubmissionResult::TooHigh),
+            "too_low" => Ok(SubmissionResult::TooLow),
+            "wrong" => Ok(SubmissionResult::Wrong),
+            "already_solved" => Ok(SubmissionResult::AlreadySolved),
+            "rate_limited" => Ok(SubmissionResult::RateLimited { wait: None }),
+            "unknown" => Ok(SubmissionResult::Unknown),
+            s => Err(format!("Unknown submission result `{s}`.")),
+        }
+    }
+}
+
+impl SubmissionLog {
+    /// Parse the lines of a log, returning the line numbers and errors of the lines that were skipped.
+    fn parse(contents: &str) -> (Self, Vec<(usize, String)>) {
+        let mut log = SubmissionLog::default();
+        let mut errors = vec![];
+
+        for (i, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let entry = JsonValue::from_str(line)
+                .or(Err("not valid JSON line.".to_string()))
+                .and_then(|json| SubmissionEntry::try_from(&json));
+
+            match entry {
+                Ok(entry) => log.entries.push(entry),
+                Err(e) => errors.push((i + 1, e)),
+            }
+        }
+
+        (log, errors)
+    }
+}
+
+impl From<&SubmissionEntry> for JsonValue {
+    fn from(value: &SubmissionEntry) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "result".into(),
+            JsonValue::String(value.result.as_str().into()),
+        );
+
+        if let SubmissionResult::RateLimited { wait: Some(wait) } = value.result {
+            map.insert(
+                "wait_seconds".into(),
+                JsonValue::Number(wait.as_secs() as f64),
+            );
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for SubmissionEntry {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>())
+                .copied()
+                .ok_or(format!("Expected submission.{key} to be a number."))
+        };
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .cloned()
+                .ok_or(format!("Expected submission.{key} to be a string."))
+        };
+
+        let result = match string("result")?.parse()? {
+            SubmissionResult::RateLimited { .. } => SubmissionResult::RateLimited {
+                wait: number("wait_seconds")
+                    .ok()
+                    .map(|secs| Duration::from_secs(secs as u64)),
+            },
+            result => result,
+        };
+
+        Ok(SubmissionEntry {
+            timestamp: number("timestamp")? as u64,
+            part: number("part")? as u8,
+            answer: string("answer")?,
+            result,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{SubmissionEntry, SubmissionLog, SubmissionResult};
+    use std::time::Duration;
+    use tinyjson::JsonValue;
+
+    fn entry(part: u8, answer: &str, result: SubmissionResult) -> SubmissionEntry {
+        SubmissionEntry {
+            timestamp: 0,
+            part,
+            answer: answer.into(),
+            result,
+        }
+    }
+
+    #[test]
+    fn parses_responses() {
+        let parse = SubmissionResult::from_response;
+        assert_eq!(
+            parse("That's the right answer! You are one gold star closer."),
+            SubmissionResult::Correct
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too high. If you're stuck..."),
+            SubmissionResult::TooHigh
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too low."),
+            SubmissionResult::TooLow
+        );
+        assert_eq!(
+            parse("That's not the right answer. If you're stuck, make sure..."),
+            SubmissionResult::Wrong
+        );
+        assert_eq!(
+            parse("You don't seem to be solving the right level. Did you already complete it?"),
+            SubmissionResult::AlreadySolved
+        );
+        assert_eq!(parse("<html>"), SubmissionResult::Unknown);
+    }
+
+    #[test]
+    fn parses_wait_times() {
+        assert_eq!(
+            SubmissionResult::from_response(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 30s left to wait."
+            ),
+            SubmissionResult::RateLimited {
+                wait: Some(Duration::from_secs(90))
+            }
+        );
+        assert_eq!(
+            SubmissionResult::from_response("You gave an answer too recently."),
+            SubmissionResult::RateLimited { wait: None }
+        );
+    }
+
+    #[test]
+    fn roundtrips_entries() {
+        let entries = [
+            entry(1, "42", SubmissionResult::TooHigh),
+            entry(
+                2,
+                "7",
+                SubmissionResult::RateLimited {
+                    wait: Some(Duration::from_secs(30)),
+                },
+            ),
+        ];
+
+        let lines = entries
+            .iter()
+            .map(|entry| JsonValue::from(entry).stringify().unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let (log, errors) = SubmissionLog::parse(&lines);
+        assert_eq!(log.entries, entries);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn skips_corrupt_lines() {
+        let lines = [
+            JsonValue::from(&entry(1, "42", SubmissionResult::Wrong))
+                .stringify()
+                .unwrap(),
+            r#"{"timestamp":0,"part":1,"ans"#.into(),
+            JsonValue::from(&entry(1, "43", SubmissionResult::TooLow))
+                .stringify()
+                .unwrap(),
+        ]
+        .join("\n");
+
+        let (log, errors) = SubmissionLog::parse(&lines);
+        assert_eq!(log.entries.len(), 2);
+        assert!(log.find_wrong(1, "42").is_some());
+        assert!(log.check_bounds(1, "40").is_some());
+        assert_eq!(errors, [(2, "not valid JSON line.".to_string())]);
+    }
+
+    #[test]
+    fn finds_wrong_answers() {
+        let log = SubmissionLog {
+            entries: vec![
+                entry(1, "42", SubmissionResult::Wrong),
+                entry(1, "43", SubmissionResult::RateLimited { wait: None }),
+            ],
+        };
+        assert!(log.find_wrong(1, "42").is_some());
+        assert!(log.find_wrong(2, "42").is_none());
+        assert!(log.find_wrong(1, "43").is_none());
+    }
+
+    #[test]
+    fn computes_remaining_wait() {
+        let rate_limited = SubmissionEntry {
+            timestamp: 100,
+            ..entry(
+                1,
+                "42",
+                SubmissionResult::RateLimited {
+                    wait: Some(Duration::from_secs(30)),
+                },
+            )
+        };
+        let log = SubmissionLog {
+            entries: vec![rate_limited.clone()],
+        };
+        assert_eq!(log.remaining_wait(110), Some(Duration::from_secs(20)));
+        assert_eq!(log.remaining_wait(130), None);
+
+        let log = SubmissionLog {
+            entries: vec![rate_limited, entry(1, "43", SubmissionResult::TooLow)],
+        };
+        assert_eq!(log.remaining_wait(110), None);
+
+        let log = SubmissionLog {
+            entries: vec![entry(1, "42", SubmissionResult::RateLimited { wait: None })],
+        };
+        assert_eq!(log.remaining_wait(0), None);
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let log = SubmissionLog {
+            entries: vec![
+                entry(1, "100", SubmissionResult::TooHigh),
+                entry(1, "80", SubmissionResult::TooHigh),
+                entry(1, "10", SubmissionResult::TooLow),
+            ],
+        };
+        assert_eq!(log.check_bounds(1, "50"), None);
+        assert!(log.check_bounds(1, "90").is_some());
+        assert!(log.check_bounds(1, "80").is_some());
+        assert!(log.check_bounds(1, "5").is_some());
+        assert_eq!(log.check_bounds(2, "90"), None);
+        assert_eq!(log.check_bounds(1, "abc"), None);
+    }
+}
@@ -0,0 +1,263 @@
+//! Module templates used by `cargo scaffold`.
+//! Templates are read from `templates/{name}.txt`, falling back to the built-in `src/template.txt` for `default`.
+//!
+//! Templates can use the variables `%YEAR%`, `%DAY%` (zero-padded), `%DAY_NUMBER%` and `%TITLE%`,
+//! and optional sections that are only kept if they are enabled with `--with <section>`. Sections can be nested:
+//!
+//! ```text
+//! %IF parse%
+//! advent_of_code::solution!(%YEAR%, %DAY_NUMBER%, parse);
+//! %ELSE%
+//! advent_of_code::solution!(%YEAR%, %DAY_NUMBER%);
+//! %END%
+//! ```
+
+use std::{collections::BTreeSet, fs, path::Path};
+
+use crate::template::{Puzzle, aoc_cli::get_puzzle_path};
+
+const DEFAULT_TEMPLATE: &str =
+    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+
+pub static TEMPLATES_DIR: &str = "templates";
+pub static DEFAULT_NAME: &str = "default";
+
+pub struct Template {
+    pub name: String,
+    pub contents: String,
+}
+
+/// Values substituted for the variables of a template.
+pub struct Variables {
+    pub puzzle: Puzzle,
+    /// Title of the puzzle, e.g. `Day 1: Secret Entrance`.
+    pub title: String,
+}
+
+impl Variables {
+    /// Reads the title from the downloaded puzzle description, if there is one.
+    pub fn new(puzzle: Puzzle) -> Self {
+        let title = fs::read_to_string(get_puzzle_path(puzzle))
+            .ok()
+            .and_then(|markdown| extract_title(&markdown))
+            .unwrap_or_else(|| format!("Day {}", puzzle.day.into_inner()));
+
+        Self { puzzle, title }
+    }
+
+    fn substitute(&self, line: &str) -> String {
+        line.replace("%YEAR%", &self.puzzle.year.to_string())
+            .replace("%DAY_NUMBER%", &self.puzzle.day.into_inner().to_string())
+            .replace("%DAY%", &self.puzzle.day.to_string())
+            .replace("%TITLE%", &self.title)
+    }
+}
+
+/// The title of a puzzle description, e.g. `Day 1: Secret Entrance` for `## --- Day 1: Secret Entrance ---`.
+fn extract_title(markdown: &str) -> Option<String> {
+    let title = markdown
+        .lines()
+        .next()?
+        .strip_prefix("## --- ")?
+        .strip_suffix(" ---")?;
+    Some(title.to_string())
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Load the template `name`, or `templates/default.txt` if no name is given.
+/// `default` falls back to the built-in template if the repository does not override it.
+pub fn load(name: Option<&str>) -> Result<Template, String> {
+    let name = name.unwrap_or(DEFAULT_NAME);
+    let path = Path::new(TEMPLATES_DIR).join(format!("{name}.txt"));
+
+    match fs::read_to_string(&path) {
+        Ok(contents) => Ok(Template {
+            name: name.into(),
+            contents,
+        }),
+        Err(_) if name == DEFAULT_NAME => Ok(Template {
+            name: name.into(),
+            contents: DEFAULT_TEMPLATE.into(),
+        }),
+        Err(_) => Err(format!(
+            "template `{name}` not found at \"{}\". Available templates: {}.",
+            path.display(),
+            list().join(", ")
+        )),
+    }
+}
+
+/// Names of all available templates, sorted.
+pub fn list() -> Vec<String> {
+    let mut names: BTreeSet<String> = BTreeSet::from([DEFAULT_NAME.into()]);
+
+    if let Ok(entries) = fs::read_dir(TEMPLATES_DIR) {
+        names.extend(entries.flatten().filter_map(|entry| {
+            let path = entry.path();
+            match path.extension() {
+                Some(ext) if ext == "txt" => Some(path.file_stem()?.to_str()?.to_string()),
+                _ => None,
+            }
+        }));
+    }
+
+    names.into_iter().collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+enum Directive<'a> {
+    If(&'a str),
+    Else,
+    End,
+}
+
+fn parse_directive(line: &str) -> Option<Directive<'_>> {
+    match line.trim() {
+        "%ELSE%" => Some(Directive::Else),
+        "%END%" => Some(Directive::End),
+        line => line
+            .strip_prefix("%IF ")
+            .and_then(|rest| rest.strip_suffix('%'))
+            .map(|section| Directive::If(section.trim())),
+    }
+}
+
+/// Names of the optional sections of a template.
+pub fn sections(template: &Template) -> Vec<String> {
+    let sections: BTreeSet<String> = template
+        .contents
+        .lines()
+        .filter_map(|line| match parse_directive(line)? {
+            Directive::If(section) => Some(section.to_string()),
+            _ => None,
+        })
+        .collect();
+    sections.into_iter().collect()
+}
+
+/// Render `template`, keeping the sections listed in `enabled` and substituting all variables.
+/// Fails for sections the template does not have, so typos do not go unnoticed.
+pub fn render(
+    template: &Template,
+    variables: &Variables,
+    enabled: &[String],
+) -> Result<String, String> {
+    let available = sections(template);
+
+    if let Some(unknown) = enabled.iter().find(|section| !available.contains(section)) {
+        let available = if available.is_empty() {
+            "none".into()
+        } else {
+            available.join(", ")
+        };
+        return Err(format!(
+            "template `{}` has no section `{unknown}`. Available sections: {available}.",
+            template.name
+        ));
+    }
+
+    let mut out = String::new();
+    // NOTE: whether the content of each open section is kept, innermost last.
+    let mut sections: Vec<bool> = vec![];
+
+    for (i, line) in template.contents.lines().enumerate() {
+        let error =
+            |message: &str| format!("template `{}`, line {}: {message}", template.name, i + 1);
+
+        match parse_directive(line) {
+            Some(Directive::If(name)) => {
+                sections.push(enabled.iter().any(|section| section == name))
+            }
+            Some(Directive::Else) => match sections.last_mut() {
+                Some(keep) => *keep = !*keep,
+                None => return Err(error("`%ELSE%` outside of a section.")),
+            },
+            Some(Directive::End) => {
+                sections
+                    .pop()
+                    .ok_or_else(|| error("`%END%` outside of a section."))?;
+            }
+            None if sections.iter().all(|keep| *keep) => {
+                out.push_str(&variables.substitute(line));
+                out.push('\n');
+            }
+            None => {}
+        }
+    }
+
+    if !sections.is_empty() {
+        return Err(format!(
+            "template `{}`: a section is missing its `%END%`.",
+            template.name
+        ));
+    }
+
+    Ok(out)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{Template, Variables, extract_title, render};
+    use crate::puzzle;
+
+    fn get_template(contents: &str) -> Template {
+        Template {
+            name: "test".into(),
+            contents: contents.into(),
+        }
+    }
+
+    fn get_variables() -> Variables {
+        Variables {
+            puzzle: puzzle!(2025, 5),
+            title: "Day 5: Cafeteria".into(),
+        }
+    }
+
+    #[test]
+    fn substitutes_variables() {
+        let template = get_template("// %TITLE%\nsolution!(%YEAR%, %DAY_NUMBER%); // %DAY%");
+        assert_eq!(
+            render(&template, &get_variables(), &[]).unwrap(),
+            "// Day 5: Cafeteria\nsolution!(2025, 5); // 05\n"
+        );
+    }
+
+    #[test]
+    fn renders_optional_sections() {
+        let template = get_template("a\n%IF parse%\nb\n%ELSE%\nc\n%END%\n%IF bench%\nd\n%END%\ne");
+        let variables = get_variables();
+
+        assert_eq!(render(&template, &variables, &[]).unwrap(), "a\nc\ne\n");
+        assert_eq!(
+            render(&template, &variables, &["parse".into(), "bench".into()]).unwrap(),
+            "a\nb\nd\ne\n"
+        );
+
+        let error = render(&template, &variables, &["grid".into()]).unwrap_err();
+        assert!(
+            error.contains("Available sections: bench, parse."),
+            "{error}"
+        );
+
+        let nested = get_template("%IF a%\n%IF b%\nb\n%ELSE%\nc\n%END%\n%END%");
+        assert_eq!(render(&nested, &variables, &["a".into()]).unwrap(), "c\n");
+        assert_eq!(render(&nested, &variables, &["b".into()]).unwrap(), "");
+
+        assert!(render(&get_template("%IF a%\nb"), &variables, &[]).is_err());
+        assert!(render(&get_template("%END%"), &variables, &[]).is_err());
+    }
+
+    #[test]
+    fn extracts_titles() {
+        assert_eq!(
+            extract_title("## --- Day 5: Cafeteria ---\n\nText").as_deref(),
+            Some("Day 5: Cafeteria")
+        );
+        assert_eq!(extract_title("Text"), None);
+    }
+}
@@ -1,17 +1,89 @@
-use std::{collections::HashMap, fs, io::Error, str::FromStr};
+use std::{collections::HashMap, fs, io::Error, str::FromStr, time::Duration};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::alloc::AllocStats;
+use crate::template::protocol::PARSE_PART;
+use crate::template::stats::BenchStats;
+use crate::template::{Day, Puzzle, Year};
+
+pub static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+
+/// Version of the JSON schema written by [`Timings::store_file`].
+/// Files without a `version` key were written with schema 1, which stored display strings.
+/// Schema 3 added the year to each timing.
+const SCHEMA_VERSION: u8 = 3;
+
+/// Benchmark result of a single part.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PartTiming {
+    /// Mean duration in nanoseconds.
+    pub nanos: u64,
+    /// Number of measured samples. `0` for timings migrated from schema 1, which did not record it.
+    pub samples: u64,
+    /// Distribution of the samples, if it was recorded.
+    pub stats: Option<BenchStats>,
+    /// Allocations of the part, if it was benched with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+}
+
+impl From<BenchStats> for PartTiming {
+    fn from(stats: BenchStats) -> Self {
+        Self {
+            nanos: stats.mean.as_nanos() as u64,
+            samples: stats.samples as u64,
+            stats: Some(stats),
+            alloc: None,
+        }
+    }
+}
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+impl PartTiming {
+    pub fn duration(&self) -> Duration {
+        Duration::from_nanos(self.nanos)
+    }
+}
 
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
+    pub year: Year,
     pub day: Day,
-    pub part_1: Option<String>,
-    pub part_2: Option<String>,
-    pub total_nanos: f64,
+    /// Name of the input the day was benched with, `None` for the default input.
+    pub input: Option<String>,
+    /// Parse phase shared by both parts, for solutions that declare a `parse` function.
+    pub parse: Option<PartTiming>,
+    pub part_1: Option<PartTiming>,
+    pub part_2: Option<PartTiming>,
+}
+
+impl Timing {
+    pub fn puzzle(&self) -> Puzzle {
+        Puzzle::new(self.year, self.day)
+    }
+
+    /// Timing of a part, `PARSE_PART` refers to the parse phase.
+    pub fn part(&self, part: u8) -> Option<&PartTiming> {
+        match part {
+            PARSE_PART => self.parse.as_ref(),
+            1 => self.part_1.as_ref(),
+            2 => self.part_2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Mean duration of a part in nanoseconds.
+    pub fn part_nanos(&self, part: u8) -> Option<u64> {
+        self.part(part).map(|p| p.nanos)
+    }
+
+    /// Sum of the mean durations of the parse phase and both parts in nanoseconds.
+    pub fn total_nanos(&self) -> u64 {
+        [&self.parse, &self.part_1, &self.part_2]
+            .into_iter()
+            .flatten()
+            .map(|p| p.nanos)
+            .sum()
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -30,14 +102,30 @@ impl Timings {
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
-    pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
-            .map_err(|x| x.to_string())
-            .and_then(Timings::try_from)
-            .unwrap_or_default()
+    /// Files written with an older schema are migrated and stored in the current schema.
+    /// Files that can not be parsed are left untouched, so they are not overwritten with empty timings.
+    pub fn read_from_file() -> Result<Self, String> {
+        let Ok(contents) = fs::read_to_string(TIMINGS_FILE_PATH) else {
+            return Ok(Timings::default());
+        };
+
+        let is_outdated = schema_version(&contents).is_some_and(|v| v < SCHEMA_VERSION);
+
+        let timings = Timings::try_from(contents)
+            .map_err(|e| format!("Failed to read \"{TIMINGS_FILE_PATH}\": {e}"))?;
+
+        if is_outdated {
+            match timings.store_file() {
+                Ok(()) => println!("Migrated \"{TIMINGS_FILE_PATH}\" to schema {SCHEMA_VERSION}."),
+                Err(e) => eprintln!("Failed to migrate \"{TIMINGS_FILE_PATH}\": {e}"),
+            }
+        }
+
+        Ok(timings)
     }
 
     /// Merge two sets of timings, overwriting `self` with `other` if present.
+    /// Timings of the same day with different inputs are kept side by side.
     pub fn merge(&self, new: &Self) -> Self {
         let mut data: Vec<Timing> = vec![];
 
@@ -46,33 +134,101 @@ impl Timings {
         }
 
         for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
+            if !data
+                .iter()
+                .any(|t| t.puzzle() == timing.puzzle() && t.input == timing.input)
+            {
                 data.push(timing.clone());
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by(|a, b| (a.puzzle(), &a.input).cmp(&(b.puzzle(), &b.input)));
         Timings { data }
     }
 
     /// Sum up total duration of timings as millis.
     pub fn total_millis(&self) -> f64 {
-        self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
+        self.data.iter().map(Timing::total_nanos).sum::<u64>() as f64 / 1_000_000_f64
+    }
+
+    /// Whether both parts of `puzzle` were benched with `input`, `None` for the default input.
+    pub fn is_day_complete(&self, puzzle: Puzzle, input: Option<&str>) -> bool {
+        self.data.iter().any(|t| {
+            t.puzzle() == puzzle
+                && t.input.as_deref() == input
+                && t.part_1.is_some()
+                && t.part_2.is_some()
+        })
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
+    /// Compare `new` timings against `self` for every part that was timed in both.
+    /// Days benched with a different input are skipped, their timings are not comparable.
+    pub fn compare(&self, new: &Self) -> Vec<TimingDelta> {
+        new.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .filter_map(|timing| {
+                let stored = self
+                    .data
+                    .iter()
+                    .find(|t| t.puzzle() == timing.puzzle() && t.input == timing.input)?;
+                Some((timing, stored))
+            })
+            .flat_map(|(timing, stored)| {
+                [PARSE_PART, 1, 2].into_iter().filter_map(|part| {
+                    Some(TimingDelta {
+                        day: timing.day,
+                        part,
+                        previous_nanos: stored.part_nanos(part)?,
+                        current_nanos: timing.part_nanos(part)?,
+                    })
+                })
+            })
+            .collect()
+    }
+}
+
+/// Change of a part's benchmark between two sets of timings.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimingDelta {
+    pub day: Day,
+    pub part: u8,
+    pub previous_nanos: u64,
+    pub current_nanos: u64,
+}
+
+impl TimingDelta {
+    /// Relative change in percent, positive values are slowdowns.
+    pub fn change_percent(&self) -> f64 {
+        if self.previous_nanos == 0 {
+            return 0.0;
+        }
+        (self.current_nanos as f64 - self.previous_nanos as f64) / self.previous_nanos as f64
+            * 100.0
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
+/// Returns the schema version of a timings document, `None` if it is not valid JSON.
+fn schema_version(value: &str) -> Option<u8> {
+    let json = JsonValue::from_str(value).ok()?;
+    let version = json
+        .get::<HashMap<String, JsonValue>>()?
+        .get("version")
+        .and_then(|v| v.get::<f64>().copied())
+        .map_or(1, |v| v as u8);
+    Some(version)
+}
+
 impl From<Timings> for JsonValue {
     fn from(value: Timings) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert(
+            "version".into(),
+            JsonValue::Number(f64::from(SCHEMA_VERSION)),
+        );
+
         map.insert(
             "data".into(),
             JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
@@ -111,26 +267,35 @@ impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert("year".into(), JsonValue::String(value.year.to_string()));
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
-        map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
-        let part_1 = value.part_1.clone().map(JsonValue::String);
-        let part_2 = value.part_2.clone().map(JsonValue::String);
+        if let Some(input) = &value.input {
+            map.insert("input".into(), JsonValue::String(input.clone()));
+        }
+
+        map.insert(
+            "parse".into(),
+            value
+                .parse
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
 
         map.insert(
             "part_1".into(),
-            match part_1 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            value
+                .part_1
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
         );
 
         map.insert(
             "part_2".into(),
-            match part_2 {
-                Some(x) => x,
-                None => JsonValue::Null,
-            },
+            value
+                .part_2
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
         );
 
         JsonValue::Object(map)
@@ -151,76 +316,334 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
-        let part_1 = json
-            .get("part_1")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_1 to be null or string.")?;
+        // NOTE: schema 2 and older did not record the year, their timings belong to the configured year.
+        let year = match json.get("year") {
+            Some(v) => v
+                .get::<String>()
+                .and_then(|year| Year::from_str(year).ok())
+                .ok_or("Expected timing.year to be a Year struct.")?,
+            None => Year::from_env(),
+        };
 
-        let part_2 = json
-            .get("part_2")
-            .map(|v| if v.is_null() { None } else { v.get::<String>() })
-            .ok_or("Expected timing.part_2 to be null or string.")?;
+        let part = |key: &str| -> Result<Option<PartTiming>, String> {
+            match json.get(key) {
+                Some(JsonValue::Null) => Ok(None),
+                Some(JsonValue::String(legacy)) => {
+                    migrate_legacy_part(legacy, json.get(&format!("{key}_stats"))).map(Some)
+                }
+                Some(v) => PartTiming::try_from(v).map(Some),
+                None => Err(format!("Expected timing.{key} to be null or an object.")),
+            }
+        };
 
-        let total_nanos = json
-            .get("total_nanos")
-            .and_then(|v| v.get::<f64>().copied())
-            .ok_or("Expected timing.total_nanos to be a number.")?;
+        // NOTE: the parse phase is optional, timings without it omit the key.
+        let parse = match json.get("parse") {
+            Some(v) if !v.is_null() => Some(PartTiming::try_from(v)?),
+            _ => None,
+        };
+
+        // NOTE: timings of the default input omit the key.
+        let input = json.get("input").and_then(|v| v.get::<String>()).cloned();
 
         Ok(Timing {
+            year,
             day,
-            part_1: part_1.cloned(),
-            part_2: part_2.cloned(),
-            total_nanos,
+            input,
+            parse,
+            part_1: part("part_1")?,
+            part_2: part("part_2")?,
         })
     }
 }
 
+/// Schema 1 stored parts as display strings (`"74.13ms"`), optionally with their stats next to them.
+fn migrate_legacy_part(formatted: &str, stats: Option<&JsonValue>) -> Result<PartTiming, String> {
+    match stats {
+        Some(v) if !v.is_null() => Ok(PartTiming::from(BenchStats::try_from(v)?)),
+        _ => Ok(PartTiming {
+            nanos: parse_duration(formatted)
+                .ok_or(format!("Could not parse legacy timing `{formatted}`."))?
+                as u64,
+            samples: 0,
+            stats: None,
+            alloc: None,
+        }),
+    }
+}
+
+fn parse_duration(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    let parse = |postfix: &str| s.strip_suffix(postfix)?.parse::<f64>().ok();
+
+    match s {
+        s if s.ends_with("ns") => parse("ns"),
+        s if s.ends_with("µs") => parse("µs").map(|x| x * 1000_f64),
+        s if s.ends_with("ms") => parse("ms").map(|x| x * 1_000_000_f64),
+        _ => parse("s").map(|x| x * 1_000_000_000_f64),
+    }
+}
+
 /* -------------------------------------------------------------------------- */
 
-#[cfg(feature = "test_lib")]
-mod tests {
-    use crate::day;
+impl From<&PartTiming> for JsonValue {
+    fn from(value: &PartTiming) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("nanos".into(), JsonValue::Number(value.nanos as f64));
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert(
+            "stats".into(),
+            value
+                .stats
+                .as_ref()
+                .map_or(JsonValue::Null, JsonValue::from),
+        );
+
+        if let Some(alloc) = &value.alloc {
+            map.insert("alloc".into(), JsonValue::from(alloc));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartTiming {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part timing to be a JSON object.")?;
+
+        let nanos = json
+            .get("nanos")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected part.nanos to be a number.")?;
+
+        let samples = json
+            .get("samples")
+            .and_then(|v| v.get::<f64>().copied())
+            .ok_or("Expected part.samples to be a number.")?;
+
+        let stats = match json.get("stats") {
+            Some(v) if !v.is_null() => Some(BenchStats::try_from(v)?),
+            _ => None,
+        };
+
+        let alloc = match json.get("alloc") {
+            Some(v) if !v.is_null() => Some(AllocStats::try_from(v)?),
+            _ => None,
+        };
+
+        Ok(PartTiming {
+            nanos: nanos as u64,
+            samples: samples as u64,
+            stats,
+            alloc,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&BenchStats> for JsonValue {
+    fn from(value: &BenchStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        for (key, duration) in [
+            ("mean_nanos", value.mean),
+            ("min_nanos", value.min),
+            ("median_nanos", value.median),
+            ("p95_nanos", value.p95),
+            ("std_dev_nanos", value.std_dev),
+        ] {
+            map.insert(key.into(), JsonValue::Number(duration.as_nanos() as f64));
+        }
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for BenchStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected timing stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected timing stats.{key} to be a number."))
+        };
+
+        let duration = |key: &str| number(key).map(|x| Duration::from_nanos(x as u64));
+
+        Ok(BenchStats {
+            samples: number("samples")? as u128,
+            mean: duration("mean_nanos")?,
+            min: duration("min_nanos")?,
+            median: duration("median_nanos")?,
+            p95: duration("p95_nanos")?,
+            std_dev: duration("std_dev_nanos")?,
+            outliers: number("outliers")? as u128,
+        })
+    }
+}
+
+impl From<&AllocStats> for JsonValue {
+    fn from(value: &AllocStats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("bytes".into(), JsonValue::Number(value.bytes as f64));
+        map.insert(
+            "allocations".into(),
+            JsonValue::Number(value.allocations as f64),
+        );
+        map.insert(
+            "peak_bytes".into(),
+            JsonValue::Number(value.peak_bytes as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for AllocStats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected alloc stats to be a JSON object.")?;
 
-    use super::{Timing, Timings};
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .map(|v| v as u64)
+                .ok_or(format!("Expected alloc stats.{key} to be a number."))
+        };
+
+        Ok(AllocStats {
+            bytes: number("bytes")?,
+            allocations: number("allocations")?,
+            peak_bytes: number("peak_bytes")?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Fixtures shared by the tests of the modules that work with timings.
+#[cfg(all(test, feature = "test_lib"))]
+pub mod fixtures {
+    use super::{PartTiming, Timing, Timings};
+    use crate::template::Day;
+    use crate::{day, year};
+
+    /// Timing of a part that took `millis` on average over 10 samples.
+    pub fn part(millis: u64) -> Option<PartTiming> {
+        Some(PartTiming {
+            nanos: millis * 1_000_000,
+            samples: 10,
+            stats: None,
+            alloc: None,
+        })
+    }
+
+    /// Timing of `day` of 2025 with the default input and without a parse phase.
+    pub fn timing(day: Day, part_1: Option<PartTiming>, part_2: Option<PartTiming>) -> Timing {
+        Timing {
+            year: year!(2025),
+            day,
+            input: None,
+            parse: None,
+            part_1,
+            part_2,
+        }
+    }
 
-    fn get_mock_timings() -> Timings {
+    /// Timings of days 1 and 2 of 2025, and of day 4, which only has part one benched.
+    pub fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
-                Timing {
-                    day: day!(1),
-                    part_1: Some("10ms".into()),
-                    part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
-                },
-                Timing {
-                    day: day!(2),
-                    part_1: Some("30ms".into()),
-                    part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
-                },
-                Timing {
-                    day: day!(4),
-                    part_1: Some("40ms".into()),
-                    part_2: None,
-                    total_nanos: 4e+10,
-                },
+                timing(day!(1), part(10), part(20)),
+                timing(day!(2), part(30), part(40)),
+                timing(day!(4), part(40), None),
             ],
         }
     }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{day, template::timings::Timings, year};
 
         #[test]
         fn handles_json_timings() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "part_1": { "nanos": 1000001, "samples": 10, "stats": null }, "part_2": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 1);
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.day, day!(1));
+            assert_eq!(timing.part_1.unwrap().nanos, 1_000_001);
+            assert_eq!(timing.part_1.unwrap().samples, 10);
+            assert_eq!(timing.part_2, None);
+            assert_eq!(timing.total_nanos(), 1_000_001);
+        }
+
+        #[test]
+        fn handles_parse_timings() {
+            let json = r#"{ "version": 2, "data": [{ "day": "01", "parse": { "nanos": 5, "samples": 10, "stats": null }, "part_1": { "nanos": 10, "samples": 10, "stats": null }, "part_2": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert_eq!(timing.parse.unwrap().nanos, 5);
+            assert_eq!(timing.total_nanos(), 15);
+        }
+
+        #[test]
+        fn migrates_legacy_timings() {
             let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
             assert_eq!(timing.day, day!(1));
-            assert_eq!(timing.part_1, Some("1ms".to_string()));
+            assert_eq!(timing.part_1.unwrap().nanos, 1_000_000);
+            assert_eq!(timing.part_1.unwrap().samples, 0);
             assert_eq!(timing.part_2, None);
-            assert_eq!(timing.total_nanos, 1_000_000_000_f64);
+        }
+
+        #[test]
+        fn migrates_legacy_timings_with_stats() {
+            let json = r#"{ "data": [{ "day": "01", "part_1": "74.1ns", "part_2": null, "total_nanos": 74, "part_1_stats": { "samples": 100, "mean_nanos": 74131, "min_nanos": 70, "median_nanos": 73, "p95_nanos": 80, "std_dev_nanos": 2, "outliers": 3 } }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let part_1 = timings.data[0].part_1.unwrap();
+            assert_eq!(part_1.nanos, 74131);
+            assert_eq!(part_1.samples, 100);
+            assert_eq!(part_1.stats.unwrap().outliers, 3);
+        }
+
+        #[test]
+        fn detects_schema_versions() {
+            assert_eq!(super::super::schema_version(r#"{ "data": [] }"#), Some(1));
+            assert_eq!(
+                super::super::schema_version(r#"{ "version": 2, "data": [] }"#),
+                Some(2)
+            );
+            assert_eq!(super::super::schema_version("not json"), None);
+        }
+
+        #[test]
+        fn handles_timings_of_other_years() {
+            let json = r#"{ "version": 3, "data": [{ "year": "2024", "day": "01", "part_1": null, "part_2": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].year, year!(2024));
         }
 
         #[test]
@@ -246,14 +669,15 @@ mod tests {
     }
 
     mod serialization {
-        use super::get_mock_timings;
+        use crate::template::timings::fixtures::get_mock_timings;
+        use crate::template::{alloc::AllocStats, timings::Timings};
         use std::collections::HashMap;
         use tinyjson::JsonValue;
 
         #[test]
         fn serializes_timings() {
             let timings = get_mock_timings();
-            let value = JsonValue::try_from(timings).unwrap();
+            let value = JsonValue::from(timings);
             assert_eq!(
                 value
                     .get::<HashMap<String, JsonValue>>()
@@ -266,54 +690,153 @@ mod tests {
                 3
             );
         }
+
+        #[test]
+        fn roundtrips_timings() {
+            let mut timings = get_mock_timings();
+            timings.data[0].part_1.as_mut().unwrap().alloc = Some(AllocStats {
+                bytes: 10,
+                allocations: 1,
+                peak_bytes: 10,
+            });
+
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 3);
+            assert_eq!(timings.data[1].part_2.unwrap().nanos, 40_000_000);
+            assert_eq!(timings.data[2].part_2, None);
+            assert_eq!(timings.data[0].part_1.unwrap().alloc.unwrap().bytes, 10);
+            assert_eq!(timings.data[1].part_1.unwrap().alloc, None);
+        }
+
+        #[test]
+        fn roundtrips_input_names() {
+            let mut timings = get_mock_timings();
+            timings.data[0].input = Some("alice".into());
+
+            let json = JsonValue::from(timings).stringify().unwrap();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].input, Some("alice".into()));
+            assert_eq!(timings.data[1].input, None);
+        }
     }
 
     mod is_day_complete {
         use crate::{
-            day,
+            day, puzzle,
             template::timings::{Timing, Timings},
         };
 
+        use crate::template::timings::fixtures::{part, timing};
+
         #[test]
         fn handles_completed_days() {
+            let timings = Timings {
+                data: vec![timing(day!(1), part(1), part(2))],
+            };
+
+            assert!(timings.is_day_complete(puzzle!(2025, 1), None));
+            assert!(!timings.is_day_complete(puzzle!(2024, 1), None));
+        }
+
+        #[test]
+        fn handles_days_completed_with_other_inputs() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: Some("2ms".into()),
-                    total_nanos: 3_000_000_000_f64,
+                    input: Some("alice".into()),
+                    ..timing(day!(1), part(1), part(2))
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert!(!timings.is_day_complete(puzzle!(2025, 1), None));
+            assert!(timings.is_day_complete(puzzle!(2025, 1), Some("alice")));
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
-                data: vec![Timing {
-                    day: day!(1),
-                    part_1: Some("1ms".into()),
-                    part_2: None,
-                    total_nanos: 1_000_000_000_f64,
-                }],
+                data: vec![timing(day!(1), part(1), None)],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert!(!timings.is_day_complete(puzzle!(2025, 1), None));
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
+                data: vec![timing(day!(1), None, None)],
+            };
+
+            assert!(!timings.is_day_complete(puzzle!(2025, 1), None));
+        }
+    }
+
+    mod compare {
+        use crate::{
+            day,
+            template::timings::{Timing, Timings},
+        };
+
+        use crate::template::timings::fixtures::{get_mock_timings, part, timing};
+
+        #[test]
+        fn compares_overlapping_parts() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![
+                    timing(day!(2), part(15), part(60)),
+                    timing(day!(3), part(1), None),
+                ],
+            };
+
+            let deltas = timings.compare(&other);
+            assert_eq!(deltas.len(), 2);
+            assert_eq!(deltas[0].day, day!(2));
+            assert_eq!(deltas[0].part, 1);
+            assert_eq!(deltas[0].change_percent(), -50.0);
+            assert_eq!(deltas[1].part, 2);
+            assert_eq!(deltas[1].change_percent(), 50.0);
+        }
+
+        #[test]
+        fn compares_parse_phase() {
+            let mut timings = get_mock_timings();
+            timings.data[0].parse = part(10);
+
+            let other = Timings {
                 data: vec![Timing {
-                    day: day!(1),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0.0,
+                    parse: part(5),
+                    ..timing(day!(1), None, None)
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            let deltas = timings.compare(&other);
+            assert_eq!(deltas.len(), 1);
+            assert_eq!(deltas[0].part, 0);
+            assert_eq!(deltas[0].change_percent(), -50.0);
+        }
+
+        #[test]
+        fn skips_parts_missing_on_either_side() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![timing(day!(4), None, part(10))],
+            };
+
+            assert_eq!(timings.compare(&other).len(), 0);
+        }
+
+        #[test]
+        fn skips_days_benched_with_other_inputs() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![Timing {
+                    input: Some("alice".into()),
+                    ..timing(day!(2), part(15), part(60))
+                }],
+            };
+
+            assert_eq!(timings.compare(&other).len(), 0);
         }
     }
 
@@ -321,20 +844,16 @@ mod tests {
         use crate::{
             day,
             template::timings::{Timing, Timings},
+            year,
         };
 
-        use super::get_mock_timings;
+        use crate::template::timings::fixtures::{get_mock_timings, part, timing};
 
         #[test]
         fn handles_disjunct_timings() {
             let timings = get_mock_timings();
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(3),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![timing(day!(3), None, None)],
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
@@ -349,19 +868,14 @@ mod tests {
             let timings = get_mock_timings();
 
             let other = Timings {
-                data: vec![Timing {
-                    day: day!(2),
-                    part_1: None,
-                    part_2: None,
-                    total_nanos: 0_f64,
-                }],
+                data: vec![timing(day!(2), None, None)],
             };
             let merged = timings.merge(&other);
 
             assert_eq!(merged.data.len(), 3);
             assert_eq!(merged.data[0].day, day!(1));
             assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[1].total_nanos, 0_f64);
+            assert_eq!(merged.data[1].total_nanos(), 0);
             assert_eq!(merged.data[2].day, day!(4));
         }
 
@@ -380,5 +894,41 @@ mod tests {
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 3);
         }
+
+        #[test]
+        fn keeps_same_days_of_other_years() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![Timing {
+                    year: year!(2024),
+                    ..timing(day!(2), None, None)
+                }],
+            };
+            let merged = timings.merge(&other);
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[0].year, year!(2024));
+            assert_eq!(merged.data[1].year, year!(2025));
+        }
+
+        #[test]
+        fn keeps_timings_of_other_inputs() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![Timing {
+                    input: Some("alice".into()),
+                    ..timing(day!(2), part(5), None)
+                }],
+            };
+            let merged = timings.merge(&other);
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[1].input, None);
+            assert_eq!(merged.data[1].part_nanos(1), Some(30_000_000));
+            assert_eq!(merged.data[2].day, day!(2));
+            assert_eq!(merged.data[2].input.as_deref(), Some("alice"));
+
+            let merged = merged.merge(&other);
+            assert_eq!(merged.data.len(), 4);
+        }
     }
 }
@@ -0,0 +1,274 @@
+//! Unlock times of puzzles and waiting for them.
+//! Puzzles unlock at midnight in the timezone of the Advent of Code servers (UTC-5).
+//! The time is read from a [`Clock`], so waiting can be tested without waiting until december.
+
+use std::fmt::Display;
+use std::io::{Write, stdout};
+use std::thread;
+use std::time::Duration;
+
+use crate::template::date::{DateTime, now_timestamp};
+use crate::template::{Day, Puzzle, Year};
+
+/// Offset of the server timezone to UTC in seconds.
+const SERVER_UTC_OFFSET: i64 = -5 * 3600;
+
+/// Response of the servers to requests for a puzzle that has not unlocked yet.
+const NOT_UNLOCKED_MESSAGE: &str =
+    "Please don't repeatedly request this endpoint before it unlocks";
+/// Attempts of a request that races the unlock and the delay between them.
+const UNLOCK_ATTEMPTS: u32 = 5;
+const UNLOCK_RETRY_DELAY: Duration = Duration::from_secs(2);
+
+/// Source of the current time.
+pub trait Clock {
+    /// Seconds since the unix epoch.
+    fn now(&self) -> u64;
+    /// Block for `duration`.
+    fn sleep(&self, duration: Duration);
+}
+
+/// The system clock.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> u64 {
+        now_timestamp()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// A clock that only advances when sleeping, for tests.
+#[cfg(all(test, feature = "test_lib"))]
+pub struct MockClock {
+    pub now: std::cell::Cell<u64>,
+    pub sleeps: std::cell::Cell<u32>,
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+impl MockClock {
+    pub fn new(now: u64) -> Self {
+        Self {
+            now: now.into(),
+            sleeps: 0.into(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+impl Clock for MockClock {
+    fn now(&self) -> u64 {
+        self.now.get()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.now.set(self.now.get() + duration.as_secs());
+        self.sleeps.set(self.sleeps.get() + 1);
+    }
+}
+
+/// Seconds since the unix epoch at which `puzzle` unlocks.
+pub fn unlock_timestamp(puzzle: Puzzle) -> u64 {
+    let midnight = DateTime {
+        year: i64::from(puzzle.year.into_inner()),
+        month: 12,
+        day: u32::from(puzzle.day.into_inner()),
+        hour: 0,
+        minute: 0,
+        second: 0,
+    };
+    midnight
+        .to_timestamp()
+        .saturating_add_signed(-SERVER_UTC_OFFSET)
+}
+
+/// The puzzle of `year` that unlocked on the current day in server time, if any.
+pub fn current_puzzle(year: Year, now: u64) -> Option<Puzzle> {
+    let today = DateTime::from_timestamp(now.saturating_add_signed(SERVER_UTC_OFFSET));
+
+    if today.year != i64::from(year.into_inner()) || today.month != 12 {
+        return None;
+    }
+
+    let day = Day::new(u8::try_from(today.day).ok()?)?;
+    year.has_day(day).then_some(Puzzle::new(year, day))
+}
+
+/// The first puzzle of `year` that has not unlocked yet, `None` if all of them are unlocked.
+pub fn next_unlock(year: Year, now: u64) -> Option<Puzzle> {
+    year.days()
+        .map(|day| Puzzle::new(year, day))
+        .find(|puzzle| unlock_timestamp(*puzzle) > now)
+}
+
+/// Block until `puzzle` unlocks, printing a countdown that is updated every second.
+pub fn wait_for_unlock(clock: &dyn Clock, puzzle: Puzzle) {
+    let unlock = unlock_timestamp(puzzle);
+    let mut stdout = stdout();
+
+    loop {
+        let now = clock.now();
+        if now >= unlock {
+            break;
+        }
+
+        print!(
+            "\r⏳ Day {} of {} unlocks in {} ",
+            puzzle.day,
+            puzzle.year,
+            format_countdown(unlock - now)
+        );
+        let _ = stdout.flush();
+
+        clock.sleep(Duration::from_secs(1));
+    }
+
+    println!(
+        "\r🔓 Day {} of {} unlocked.{}",
+        puzzle.day,
+        puzzle.year,
+        " ".repeat(20)
+    );
+}
+
+/// Call `fetch` until its response no longer says that `puzzle` is locked, at most [`UNLOCK_ATTEMPTS`] times.
+/// Requests sent right at the unlock time can reach the servers a moment before the puzzle unlocks.
+pub fn retry_until_unlocked<E: Display>(
+    clock: &dyn Clock,
+    puzzle: Puzzle,
+    mut fetch: impl FnMut() -> Result<String, E>,
+) -> Result<String, E> {
+    let mut attempt = 1;
+
+    loop {
+        let response = fetch();
+        let is_locked = match &response {
+            Ok(body) => body.contains(NOT_UNLOCKED_MESSAGE),
+            Err(e) => e.to_string().contains(NOT_UNLOCKED_MESSAGE),
+        };
+
+        if !is_locked || attempt == UNLOCK_ATTEMPTS {
+            return response;
+        }
+
+        println!(
+            "⏳ Day {} of {} is not unlocked yet, retrying in {}s ({attempt}/{}).",
+            puzzle.day,
+            puzzle.year,
+            UNLOCK_RETRY_DELAY.as_secs(),
+            UNLOCK_ATTEMPTS - 1
+        );
+        clock.sleep(UNLOCK_RETRY_DELAY);
+        attempt += 1;
+    }
+}
+
+/// Format a number of seconds as `[{days}d ]HH:MM:SS`.
+pub fn format_countdown(seconds: u64) -> String {
+    let (days, seconds) = (seconds / 86_400, seconds % 86_400);
+    let time = format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    );
+
+    if days > 0 {
+        format!("{days}d {time}")
+    } else {
+        time
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{
+        Clock, MockClock, NOT_UNLOCKED_MESSAGE, UNLOCK_ATTEMPTS, current_puzzle, format_countdown,
+        next_unlock, retry_until_unlocked, unlock_timestamp, wait_for_unlock,
+    };
+    use crate::{puzzle, year};
+
+    /// 2025-12-08T05:00:00Z, the unlock of day 8.
+    const DAY_8_UNLOCK: u64 = 1_765_170_000;
+
+    #[test]
+    fn computes_unlock_times() {
+        assert_eq!(unlock_timestamp(puzzle!(2025, 8)), DAY_8_UNLOCK);
+        assert_eq!(unlock_timestamp(puzzle!(2025, 9)), DAY_8_UNLOCK + 86_400);
+    }
+
+    #[test]
+    fn finds_current_puzzle() {
+        assert_eq!(
+            current_puzzle(year!(2025), DAY_8_UNLOCK),
+            Some(puzzle!(2025, 8))
+        );
+        assert_eq!(
+            current_puzzle(year!(2025), DAY_8_UNLOCK - 1),
+            Some(puzzle!(2025, 7))
+        );
+        assert_eq!(current_puzzle(year!(2024), DAY_8_UNLOCK), None);
+        // NOTE: the 2025 event only has 12 puzzles.
+        assert_eq!(current_puzzle(year!(2025), DAY_8_UNLOCK + 5 * 86_400), None);
+    }
+
+    #[test]
+    fn finds_next_unlock() {
+        assert_eq!(next_unlock(year!(2025), 0), Some(puzzle!(2025, 1)));
+        assert_eq!(
+            next_unlock(year!(2025), DAY_8_UNLOCK - 1),
+            Some(puzzle!(2025, 8))
+        );
+        assert_eq!(
+            next_unlock(year!(2025), DAY_8_UNLOCK),
+            Some(puzzle!(2025, 9))
+        );
+        assert_eq!(next_unlock(year!(2025), DAY_8_UNLOCK + 4 * 86_400), None);
+    }
+
+    #[test]
+    fn waits_until_unlock() {
+        let clock = MockClock::new(DAY_8_UNLOCK - 3);
+        wait_for_unlock(&clock, puzzle!(2025, 8));
+        assert_eq!(clock.now(), DAY_8_UNLOCK);
+        assert_eq!(clock.sleeps.get(), 3);
+
+        let clock = MockClock::new(DAY_8_UNLOCK + 10);
+        wait_for_unlock(&clock, puzzle!(2025, 8));
+        assert_eq!(clock.sleeps.get(), 0);
+    }
+
+    #[test]
+    fn retries_until_unlocked() {
+        let clock = MockClock::new(DAY_8_UNLOCK);
+        let mut responses = vec![
+            Ok("input".to_string()),
+            Err(format!(
+                "responded with status 404: {NOT_UNLOCKED_MESSAGE}."
+            )),
+            Ok(format!("{NOT_UNLOCKED_MESSAGE}.")),
+        ];
+        let response = retry_until_unlocked(&clock, puzzle!(2025, 8), || responses.pop().unwrap());
+        assert_eq!(response, Ok("input".to_string()));
+        assert_eq!(clock.sleeps.get(), 2);
+
+        let clock = MockClock::new(DAY_8_UNLOCK);
+        let response = retry_until_unlocked(&clock, puzzle!(2025, 8), || {
+            Err::<String, _>(NOT_UNLOCKED_MESSAGE)
+        });
+        assert_eq!(response, Err(NOT_UNLOCKED_MESSAGE));
+        assert_eq!(clock.sleeps.get(), UNLOCK_ATTEMPTS - 1);
+    }
+
+    #[test]
+    fn formats_countdowns() {
+        assert_eq!(format_countdown(0), "00:00:00");
+        assert_eq!(format_countdown(3723), "01:02:03");
+        assert_eq!(format_countdown(90_061), "1d 01:01:01");
+    }
+}
@@ -0,0 +1,148 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::date::{DateTime, now_timestamp};
+use crate::template::{AllDays, Day};
+
+/// The first year Advent of Code took place.
+const FIRST_YEAR: u16 = 2015;
+
+/// The first year with 12 instead of 25 puzzles.
+const FIRST_SHORT_YEAR: u16 = 2025;
+
+/// A valid year of Advent of Code (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2025).unwrap();
+/// assert_eq!(year.to_string(), "2025")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Number of puzzles of the event: 25 until 2024, 12 since 2025.
+    /// Can be overridden per year with `AOC_EVENT_DAYS_{year}`, e.g. `AOC_EVENT_DAYS_2026=25`.
+    pub fn event_days(self) -> u8 {
+        env::var(format!("AOC_EVENT_DAYS_{}", self.0))
+            .ok()
+            .and_then(|days| days.trim().parse::<u8>().ok())
+            .filter(|days| (1..=25).contains(days))
+            .unwrap_or(if self.0 >= FIRST_SHORT_YEAR { 12 } else { 25 })
+    }
+
+    /// An iterator that yields every day of the event.
+    pub fn days(self) -> AllDays {
+        AllDays::until(self.event_days())
+    }
+
+    /// Returns `true` if the event of this year has a puzzle on `day`.
+    pub fn has_day(self, day: Day) -> bool {
+        day <= self.event_days()
+    }
+
+    /// The configured year, read from `AOC_YEAR`.
+    /// Defaults to the current year in december, the previous year otherwise.
+    pub fn from_env() -> Self {
+        env::var("AOC_YEAR")
+            .ok()
+            .and_then(|year| year.parse().ok())
+            .unwrap_or_else(|| {
+                let today = DateTime::from_timestamp(now_timestamp());
+                let year = if today.month == 12 {
+                    today.year
+                } else {
+                    today.year - 1
+                };
+                // NOTE: the system clock is never set to a date before 2015.
+                Self(u16::try_from(year).unwrap_or(FIRST_YEAR).max(FIRST_YEAR))
+            })
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const {
+            $crate::template::Year::new($year)
+                .expect("invalid year, expecting a value of 2015 or later")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::Year;
+    use crate::day;
+    use std::str::FromStr;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!(Year::from_str("2025").unwrap(), Year(2025));
+        assert!(Year::from_str("2014").is_err());
+        assert!(Year::from_str("25").is_err());
+    }
+
+    #[test]
+    fn knows_event_lengths() {
+        assert_eq!(Year(2015).days().count(), 25);
+        assert_eq!(Year(2024).days().count(), 25);
+        assert_eq!(Year(2025).days().count(), 12);
+        assert!(Year(2024).has_day(day!(25)));
+        assert!(Year(2025).has_day(day!(12)));
+        assert!(!Year(2025).has_day(day!(13)));
+    }
+}
@@ -0,0 +1,702 @@
+//! Integration tests of the template commands against a mock of the Advent of Code website.
+#![cfg(feature = "test_lib")]
+
+mod common;
+
+use common::{FAKE_AOC_CLI, MockServer, Response, Workspace, output_text};
+
+const INPUT_ROUTE: &str = "GET /2025/day/1/input";
+const PUZZLE_ROUTE: &str = "GET /2025/day/1";
+const ANSWER_ROUTE: &str = "POST /2025/day/1/answer";
+
+fn mock_day_1(answers: Vec<Response>) -> MockServer {
+    MockServer::start(vec![
+        (INPUT_ROUTE, vec![Response::ok("1\n2\n3\n")]),
+        (PUZZLE_ROUTE, vec![Response::puzzle("Day 1: Mock")]),
+        (ANSWER_ROUTE, answers),
+    ])
+}
+
+#[test]
+fn downloads_input_and_puzzle() {
+    let server = mock_day_1(vec![]);
+    let workspace = Workspace::new("download");
+
+    let output = workspace
+        .cli(&server)
+        .args(["download", "1"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", output_text(&output));
+
+    assert_eq!(
+        workspace.read("data/2025/inputs/01.txt").as_deref(),
+        Some("1\n2\n3\n")
+    );
+    assert!(
+        workspace
+            .read("data/2025/inputs/01.manifest.json")
+            .is_some()
+    );
+    assert!(
+        workspace
+            .read("data/2025/puzzles/01.md")
+            .unwrap()
+            .starts_with("## --- Day 1: Mock ---")
+    );
+    assert_eq!(
+        workspace.read("data/2025/examples/01.txt").as_deref(),
+        Some("a\nb\n")
+    );
+}
+
+#[test]
+fn skips_cached_inputs() {
+    let server = mock_day_1(vec![]);
+    let workspace = Workspace::new("download-cached");
+
+    for _ in 0..2 {
+        let output = workspace
+            .cli(&server)
+            .args(["download", "1"])
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", output_text(&output));
+    }
+
+    assert_eq!(server.count(INPUT_ROUTE), 1);
+    assert_eq!(server.count(PUZZLE_ROUTE), 2);
+
+    let output = workspace
+        .cli(&server)
+        .args(["download", "1", "--force"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", output_text(&output));
+    assert_eq!(server.count(INPUT_ROUTE), 2);
+}
+
+#[test]
+fn keeps_modified_inputs() {
+    let server = mock_day_1(vec![]);
+    let workspace = Workspace::new("download-modified");
+
+    let output = workspace
+        .cli(&server)
+        .args(["download", "1"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", output_text(&output));
+
+    workspace.write("data/2025/inputs/01.txt", "1\n2\n4\n");
+
+    let output = workspace
+        .cli(&server)
+        .args(["download", "1"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", output_text(&output));
+    assert!(output_text(&output).contains("does not match the checksum"));
+    assert_eq!(server.count(INPUT_ROUTE), 1);
+    assert_eq!(
+        workspace.read("data/2025/inputs/01.txt").as_deref(),
+        Some("1\n2\n4\n")
+    );
+
+    let output = workspace
+        .cli(&server)
+        .args(["download", "1", "--force"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", output_text(&output));
+    assert_eq!(
+        workspace.read("data/2025/inputs/01.txt").as_deref(),
+        Some("1\n2\n3\n")
+    );
+}
+
+#[test]
+fn moves_data_of_the_legacy_layout() {
+    let server = mock_day_1(vec![]);
+    let workspace = Workspace::new("legacy-layout");
+    workspace.write("data/inputs/01.txt", "1\n2\n");
+    workspace.write("data/inputs/01/alice.txt", "3\n");
+    workspace.write("data/puzzles/01.md", "## --- Day 1: Mock ---\n");
+    workspace.write("data/2025/puzzles/01.md", "newer\n");
+    workspace.write(
+        "src/bin/01.rs",
+        "advent_of_code::solution!(1);\nread_file(\"examples\", DAY);\n",
+    );
+
+    let output = workspace
+        .cli(&server)
+        .args(["time", "--history", "1"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", output_text(&output));
+
+    assert_eq!(
+        workspace.read("data/2025/inputs/01.txt").as_deref(),
+        Some("1\n2\n")
+    );
+    assert_eq!(
+        workspace.read("data/2025/inputs/01/alice.txt").as_deref(),
+        Some("3\n")
+    );
+    assert_eq!(workspace.read("data/inputs/01.txt"), None);
+
+    // NOTE: existing files are never overwritten.
+    assert_eq!(
+        workspace.read("data/2025/puzzles/01.md").as_deref(),
+        Some("newer\n")
+    );
+    assert!(workspace.read("data/puzzles/01.md").is_some());
+    assert!(output_text(&output).contains("already exists"));
+
+    assert_eq!(
+        workspace.read("src/bin/2025-01.rs").as_deref(),
+        Some("advent_of_code::solution!(2025, 1);\nread_file(\"examples\", PUZZLE);\n")
+    );
+    assert_eq!(workspace.read("src/bin/01.rs"), None);
+}
+
+#[test]
+fn sends_the_configured_user_agent() {
+    let server = mock_day_1(vec![]);
+    let workspace = Workspace::new("download-user-agent");
+
+    let output = workspace
+        .cli(&server)
+        .args(["download", "1"])
+        .env(
+            "AOC_USER_AGENT",
+            "github.com/someone/aoc by someone@example.com",
+        )
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", output_text(&output));
+
+    let requests = server.requests();
+    assert!(!requests.is_empty());
+    assert!(requests.iter().all(|request| {
+        request.user_agent.as_deref() == Some("github.com/someone/aoc by someone@example.com")
+    }));
+}
+
+#[cfg(unix)]
+#[test]
+fn downloads_with_aoc_cli() {
+    let server = mock_day_1(vec![]);
+    let workspace = Workspace::new("download-aoc-cli");
+    workspace.install_aoc_cli(FAKE_AOC_CLI);
+
+    let output = workspace
+        .cli(&server)
+        .args(["download", "1"])
+        .env("AOC_BACKEND", "aoc-cli")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", output_text(&output));
+
+    assert_eq!(
+        workspace.read("data/2025/inputs/01.txt").as_deref(),
+        Some("1\n2\n3\n")
+    );
+    assert!(
+        workspace
+            .read("data/2025/puzzles/01.md")
+            .unwrap()
+            .starts_with("## --- Day 1: Mock ---")
+    );
+    assert!(server.requests().is_empty());
+
+    let calls = workspace.read("bin/aoc-calls.txt").unwrap();
+    let calls: Vec<&str> = calls.lines().collect();
+    assert_eq!(calls.len(), 3, "{calls:?}");
+    assert_eq!(calls[0], "-V");
+    assert!(calls[1].starts_with("--overwrite --input-only --input-file "));
+    assert!(calls[1].ends_with(" --year 2025 --day 01 download"));
+    assert!(calls[2].starts_with("--overwrite --puzzle-only --puzzle-file "));
+}
+
+#[cfg(unix)]
+#[test]
+fn reports_failures_of_aoc_cli() {
+    let server = mock_day_1(vec![]);
+    let workspace = Workspace::new("download-aoc-cli-failure");
+    workspace.install_aoc_cli(FAKE_AOC_CLI);
+
+    let output = workspace
+        .cli(&server)
+        .args(["download", "1"])
+        .env("AOC_BACKEND", "aoc-cli")
+        .env("AOC_CLI_STATUS", "1")
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(output_text(&output).contains("aoc-cli exited with a non-zero status"));
+    assert_eq!(workspace.read("data/2025/inputs/01.txt"), None);
+}
+
+#[test]
+fn fails_without_session() {
+    let server = mock_day_1(vec![]);
+    let workspace = Workspace::new("download-no-session");
+
+    let output = workspace
+        .cli(&server)
+        .args(["download", "1"])
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(output_text(&output).contains("No session cookie found"));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn rejects_responses_for_invalid_sessions() {
+    let server = mock_day_1(vec![]);
+    let workspace = Workspace::new("download-invalid-session");
+
+    let output = workspace
+        .cli(&server)
+        .args(["download", "1"])
+        .env("AOC_SESSION", "expired")
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(output_text(&output).contains("Please log in"));
+    assert_eq!(workspace.read("data/2025/inputs/01.txt"), None);
+}
+
+#[test]
+fn reads_puzzles() {
+    let server = mock_day_1(vec![]);
+    let workspace = Workspace::new("read");
+
+    let output = workspace.cli(&server).args(["read", "1"]).output().unwrap();
+    assert!(output.status.success(), "{}", output_text(&output));
+
+    assert!(String::from_utf8_lossy(&output.stdout).contains("## --- Day 1: Mock ---"));
+    assert!(workspace.read("data/2025/puzzles/01.md").is_some());
+    assert_eq!(server.count(INPUT_ROUTE), 0);
+}
+
+#[test]
+fn scaffolds_with_download() {
+    let server = mock_day_1(vec![]);
+    let workspace = Workspace::new("scaffold");
+
+    let output = workspace
+        .cli(&server)
+        .args(["scaffold", "1", "--download"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", output_text(&output));
+
+    let module = workspace.read("src/bin/2025-01.rs").unwrap();
+    assert!(module.starts_with("advent_of_code::solution!(2025, 1);"));
+    assert!(module.contains("assert_eq!(result, Some(2));"));
+    assert_eq!(
+        workspace.read("data/2025/examples/01.txt").as_deref(),
+        Some("a\nb\n")
+    );
+}
+
+#[test]
+fn scaffolds_from_templates() {
+    let server = mock_day_1(vec![]);
+    let workspace = Workspace::new("scaffold-template");
+    workspace.write("data/2025/puzzles/01.md", "## --- Day 1: Mock ---\n");
+    workspace.write(
+        "templates/custom.txt",
+        "// %TITLE% (%DAY%)\n%IF parse%\nfn parse() {}\n%END%\nadvent_of_code::solution!(%YEAR%, %DAY_NUMBER%);\n",
+    );
+
+    let output = workspace
+        .cli(&server)
+        .args(["scaffold", "1", "--template", "custom", "--with", "parse"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", output_text(&output));
+    assert_eq!(
+        workspace.read("src/bin/2025-01.rs").as_deref(),
+        Some("// Day 1: Mock (01)\nfn parse() {}\nadvent_of_code::solution!(2025, 1);\n")
+    );
+
+    let output = workspace
+        .cli(&server)
+        .args(["scaffold", "2", "--template", "custom", "--with", "bench"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(output_text(&output).contains("has no section `bench`. Available sections: parse."));
+    assert_eq!(workspace.read("src/bin/2025-02.rs"), None);
+}
+
+#[test]
+fn keeps_existing_files_when_scaffolding() {
+    let server = mock_day_1(vec![]);
+    let workspace = Workspace::new("scaffold-existing");
+    workspace.write("src/bin/2025-01.rs", "// solved");
+    workspace.write("data/2025/inputs/01.txt", "1\n2\n3\n");
+
+    let output = workspace
+        .cli(&server)
+        .args(["scaffold", "1"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", output_text(&output));
+    assert!(output_text(&output).contains("Pass `--overwrite-input` to replace it."));
+    assert_eq!(
+        workspace.read("src/bin/2025-01.rs").as_deref(),
+        Some("// solved")
+    );
+    assert_eq!(
+        workspace.read("data/2025/inputs/01.txt").as_deref(),
+        Some("1\n2\n3\n")
+    );
+    assert_eq!(
+        workspace.read("data/2025/examples/01.txt").as_deref(),
+        Some("")
+    );
+
+    let output = workspace
+        .cli(&server)
+        .args(["scaffold", "1", "--overwrite", "--dry-run"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", output_text(&output));
+    assert!(output_text(&output).contains("Would replace module file"));
+    assert_eq!(
+        workspace.read("src/bin/2025-01.rs").as_deref(),
+        Some("// solved")
+    );
+
+    let output = workspace
+        .cli(&server)
+        .args(["scaffold", "1", "--overwrite-input"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", output_text(&output));
+    assert_eq!(
+        workspace.read("data/2025/inputs/01.txt").as_deref(),
+        Some("")
+    );
+    assert_eq!(
+        workspace.read("src/bin/2025-01.rs").as_deref(),
+        Some("// solved")
+    );
+}
+
+#[test]
+fn rolls_back_failed_scaffolds() {
+    let server = mock_day_1(vec![]);
+    let workspace = Workspace::new("scaffold-rollback");
+    // NOTE: a file in place of the examples directory makes creating the example fail.
+    workspace.write("data/2025/examples", "");
+
+    let output = workspace
+        .cli(&server)
+        .args(["scaffold", "1"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(output_text(&output).contains("Failed to write example file"));
+    assert_eq!(workspace.read("src/bin/2025-01.rs"), None);
+    assert_eq!(workspace.read("data/2025/inputs/01.txt"), None);
+}
+
+#[test]
+fn stores_correct_answers() {
+    let server = mock_day_1(vec![Response::correct_answer()]);
+    let workspace = Workspace::new("submit-correct");
+    workspace.write("data/2025/inputs/01.txt", "1\n2\n3\n");
+
+    let output = workspace
+        .solution(&server)
+        .args(["--submit", "1"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", output_text(&output));
+    assert!(output_text(&output).contains("correct"));
+    assert_eq!(
+        output_text(&output).matches("one gold star closer").count(),
+        1
+    );
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].body, "level=1&answer=3");
+
+    assert_eq!(
+        workspace.read("data/2025/answers/01.txt").as_deref(),
+        Some("3\n\n")
+    );
+    assert!(
+        workspace
+            .read("data/2025/submissions/01.jsonl")
+            .unwrap()
+            .contains("\"correct\"")
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn submits_with_aoc_cli() {
+    let server = mock_day_1(vec![]);
+    let workspace = Workspace::new("submit-aoc-cli");
+    workspace.install_aoc_cli(FAKE_AOC_CLI);
+    workspace.write("data/2025/inputs/01.txt", "1\n2\n3\n");
+
+    let output = workspace
+        .solution(&server)
+        .args(["--submit", "1"])
+        .env("AOC_BACKEND", "aoc-cli")
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{}", output_text(&output));
+    assert!(output_text(&output).contains("correct"));
+    assert_eq!(
+        output_text(&output).matches("one gold star closer").count(),
+        1
+    );
+    assert!(server.requests().is_empty());
+
+    assert_eq!(
+        workspace.read("bin/aoc-calls.txt").as_deref(),
+        Some("-V\n--year 2025 --day 01 submit 1 3\n")
+    );
+    assert_eq!(
+        workspace.read("data/2025/answers/01.txt").as_deref(),
+        Some("3\n\n")
+    );
+}
+
+#[test]
+fn does_not_resubmit_wrong_answers() {
+    let server = mock_day_1(vec![Response::too_high()]);
+    let workspace = Workspace::new("submit-wrong");
+    workspace.write("data/2025/inputs/01.txt", "1\n2\n3\n");
+
+    let output = workspace
+        .solution(&server)
+        .args(["--submit", "1"])
+        .output()
+        .unwrap();
+    assert!(output_text(&output).contains("wrong, too high"));
+    assert_eq!(workspace.read("data/2025/answers/01.txt"), None);
+
+    let output = workspace
+        .solution(&server)
+        .args(["--submit", "1"])
+        .output()
+        .unwrap();
+    assert!(output_text(&output).contains("Not submitting 3"));
+    assert_eq!(server.count(ANSWER_ROUTE), 1);
+}
+
+#[test]
+fn reports_rate_limits() {
+    let server = mock_day_1(vec![Response::rate_limited(), Response::correct_answer()]);
+    let workspace = Workspace::new("submit-rate-limited");
+    workspace.write("data/2025/inputs/01.txt", "1\n2\n3\n");
+
+    let output = workspace
+        .solution(&server)
+        .args(["--submit", "1"])
+        .output()
+        .unwrap();
+    assert!(output_text(&output).contains("rate-limited, wait 42s"));
+    assert_eq!(workspace.read("data/2025/answers/01.txt"), None);
+
+    let output = workspace
+        .solution(&server)
+        .args(["--submit", "1"])
+        .output()
+        .unwrap();
+    assert!(output_text(&output).contains("Not submitting 3"));
+    assert!(output_text(&output).contains("before trying again"));
+    assert_eq!(server.count(ANSWER_ROUTE), 1);
+}
+
+#[test]
+fn submits_again_after_rate_limits() {
+    let server = mock_day_1(vec![Response::correct_answer()]);
+    let workspace = Workspace::new("submit-after-rate-limit");
+    workspace.write("data/2025/inputs/01.txt", "1\n2\n3\n");
+    workspace.write(
+        "data/2025/submissions/01.jsonl",
+        "{\"timestamp\":1000,\"part\":1,\"answer\":\"3\",\"result\":\"rate_limited\",\"wait_seconds\":42}\n",
+    );
+
+    // NOTE: rate-limited answers are not known to be wrong and can be submitted again.
+    let output = workspace
+        .solution(&server)
+        .args(["--submit", "1"])
+        .output()
+        .unwrap();
+    assert!(output_text(&output).contains("correct"));
+    assert_eq!(server.count(ANSWER_ROUTE), 1);
+}
+
+#[test]
+fn reports_failed_submissions() {
+    let server = mock_day_1(vec![Response {
+        status: 500,
+        body: "Something went wrong on our end.".into(),
+    }]);
+    let workspace = Workspace::new("submit-failed");
+    workspace.write("data/2025/inputs/01.txt", "1\n2\n3\n");
+
+    let output = workspace
+        .solution(&server)
+        .args(["--submit", "1"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(output_text(&output).contains("responded with status 500"));
+    assert!(output_text(&output).contains("Something went wrong on our end."));
+}
+
+#[test]
+fn does_not_submit_without_session() {
+    let server = mock_day_1(vec![Response::correct_answer()]);
+    let workspace = Workspace::new("submit-no-session");
+    workspace.write("data/2025/inputs/01.txt", "1\n2\n3\n");
+
+    let output = workspace
+        .solution(&server)
+        .args(["--submit", "1"])
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(output_text(&output).contains("No session cookie found"));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn does_not_submit_answers_of_named_inputs() {
+    let server = mock_day_1(vec![Response::correct_answer()]);
+    let workspace = Workspace::new("submit-named-input");
+    workspace.write("data/2025/inputs/01.txt", "1\n2\n3\n");
+    workspace.write("data/2025/inputs/01/friend.txt", "1\n2\n");
+
+    for args in [
+        ["--submit", "1", "--input", "friend"].as_slice(),
+        ["--submit", "1", "--all-inputs"].as_slice(),
+    ] {
+        let output = workspace.solution(&server).args(args).output().unwrap();
+
+        assert!(!output.status.success());
+        assert!(
+            output_text(&output).contains("only answers for the default input can be submitted")
+        );
+    }
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn reports_failed_parts_without_submitting() {
+    let server = mock_day_1(vec![Response::correct_answer()]);
+    let workspace = Workspace::new("submit-failed");
+    workspace.write("data/2025/inputs/01.txt", "1\nx\n3\n");
+
+    let output = workspace
+        .solution(&server)
+        .args(["--submit", "1"])
+        .output()
+        .unwrap();
+
+    let text = output_text(&output);
+    assert!(text.contains("✘ failed"), "{text}");
+    assert!(text.contains("error: invalid input at line 2"), "{text}");
+    assert!(
+        text.contains("caused by: invalid digit found in string"),
+        "{text}"
+    );
+    assert!(text.contains("    2 | x"), "{text}");
+    assert!(text.contains("Part 2: ✖"), "{text}");
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn today_requires_an_unlocked_puzzle() {
+    let server = mock_day_1(vec![]);
+    let workspace = Workspace::new("today");
+
+    let output = workspace
+        .cli(&server)
+        .args(["today", "--year", "2015"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(output_text(&output).contains("can only be run on the first 25 days of december 2015"));
+
+    let output = workspace
+        .cli(&server)
+        .args(["today", "--year", "2015", "--wait"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        output_text(&output).contains("all puzzles of Advent of Code 2015 are already unlocked")
+    );
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn caches_leaderboards() {
+    let route = "GET /2025/leaderboard/private/view/42.json";
+    let server = MockServer::start(vec![(
+        route,
+        vec![Response::ok(
+            r#"{ "event": "2025", "owner_id": 1, "members": { "1": { "id": 1, "name": "alice", "stars": 1, "local_score": 3, "global_score": 0, "completion_day_level": { "1": { "1": { "get_star_ts": 1764565500, "star_index": 1 } } } } } }"#,
+        )],
+    )]);
+    let workspace = Workspace::new("leaderboard");
+
+    for _ in 0..2 {
+        let output = workspace
+            .cli(&server)
+            .args(["leaderboard", "42"])
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "{}", output_text(&output));
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(stdout.contains("1  alice  3      1"), "{stdout}");
+        assert!(stdout.contains("alice  00:05:00  -       -"), "{stdout}");
+    }
+
+    assert_eq!(server.count(route), 1);
+    assert!(workspace.read("data/2025/leaderboards/42.json").is_some());
+}
+
+#[test]
+fn rejects_inaccessible_leaderboards() {
+    let server = MockServer::start(vec![(
+        "GET /2025/leaderboard/private/view/42.json",
+        vec![Response::ok(
+            "<html><main>Private Leaderboard</main></html>",
+        )],
+    )]);
+    let workspace = Workspace::new("leaderboard-inaccessible");
+
+    let output = workspace
+        .cli(&server)
+        .args(["leaderboard", "42"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(output_text(&output).contains("are you a member of leaderboard 42?"));
+    assert_eq!(workspace.read("data/2025/leaderboards/42.json"), None);
+}
@@ -0,0 +1,344 @@
+//! Test harness for the template commands.
+//! [`MockServer`] stands in for the Advent of Code website with scripted responses,
+//! [`Workspace`] runs the template binaries against it in a temporary directory.
+//! [`FAKE_AOC_CLI`] stands in for the aoc-cli command-line.
+#![allow(dead_code)]
+
+use std::{
+    collections::{HashMap, VecDeque},
+    env, fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    process::{Command, Output},
+    sync::{Arc, Mutex, OnceLock},
+    thread,
+};
+
+/// Session cookie accepted by the mock server.
+pub const SESSION: &str = "test-session";
+
+/// A scripted HTTP response.
+#[derive(Clone, Debug)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    pub fn ok(body: &str) -> Self {
+        Self {
+            status: 200,
+            body: body.into(),
+        }
+    }
+
+    /// A puzzle page with an example and the expected answer of part one.
+    pub fn puzzle(title: &str) -> Self {
+        Self::ok(&format!(
+            "<html><main><article class=\"day-desc\"><h2>--- {title} ---</h2>\
+            <p>Count the lines.</p><p>For example:</p><pre><code>a\nb\n</code></pre>\
+            <p>This example has <code><em>2</em></code> lines.</p></article></main></html>"
+        ))
+    }
+
+    pub fn correct_answer() -> Self {
+        Self::answer(
+            "That's the right answer!  You are one gold star closer to decorating the North Pole.",
+        )
+    }
+
+    pub fn too_high() -> Self {
+        Self::answer(
+            "That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.  Please wait one minute before trying again.",
+        )
+    }
+
+    pub fn rate_limited() -> Self {
+        Self::answer(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 42s left to wait.",
+        )
+    }
+
+    /// The response to requests without a valid session cookie.
+    pub fn logged_out() -> Self {
+        Self {
+            status: 400,
+            body: "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n".into(),
+        }
+    }
+
+    fn answer(message: &str) -> Self {
+        Self::ok(&format!(
+            "<html><main><article><p>{message} [<a href=\"/2025/day/1\">Return to Day 1</a>]</p></article></main></html>"
+        ))
+    }
+}
+
+/// A request received by the mock server.
+#[derive(Clone, Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub cookie: Option<String>,
+    pub user_agent: Option<String>,
+    pub body: String,
+}
+
+/// A local stand-in for the Advent of Code website.
+///
+/// Responses are scripted per route, e.g. `"GET /2025/day/1/input"`. If a route has several responses,
+/// they are returned in order and the last one is repeated. Unknown routes respond with `404`,
+/// requests without the [`SESSION`] cookie are rejected like on the real website.
+pub struct MockServer {
+    url: String,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+    pub fn start(routes: Vec<(&str, Vec<Response>)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let routes: HashMap<String, VecDeque<Response>> = routes
+            .into_iter()
+            .map(|(route, responses)| (route.to_string(), responses.into()))
+            .collect();
+
+        let requests = Arc::new(Mutex::new(vec![]));
+        let log = Arc::clone(&requests);
+
+        // NOTE: the thread is detached and lives as long as the test process.
+        thread::spawn(move || {
+            let mut routes = routes;
+            for stream in listener.incoming().flatten() {
+                let Some(request) = read_request(&stream) else {
+                    continue;
+                };
+                let response = respond(&mut routes, &request);
+                log.lock().unwrap().push(request);
+                write_response(stream, &response);
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Number of received requests to `route`, e.g. `"GET /2025/day/1/input"`.
+    pub fn count(&self, route: &str) -> usize {
+        self.requests()
+            .iter()
+            .filter(|request| format!("{} {}", request.method, request.path) == route)
+            .count()
+    }
+}
+
+fn respond(routes: &mut HashMap<String, VecDeque<Response>>, request: &Request) -> Response {
+    if request.cookie.as_deref() != Some(&format!("session={SESSION}")) {
+        return Response::logged_out();
+    }
+
+    match routes.get_mut(&format!("{} {}", request.method, request.path)) {
+        Some(responses) if responses.len() > 1 => responses.pop_front().unwrap(),
+        Some(responses) => responses.front().cloned().unwrap(),
+        None => Response {
+            status: 404,
+            body: "404 Not Found".into(),
+        },
+    }
+}
+
+fn read_request(stream: &TcpStream) -> Option<Request> {
+    let mut reader = BufReader::new(stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line).ok()?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let path = parts.next()?.to_string();
+
+    let mut cookie = None;
+    let mut user_agent = None;
+    let mut content_length = 0;
+
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+
+        let (name, value) = line.split_once(':')?;
+        match name.to_ascii_lowercase().as_str() {
+            "cookie" => cookie = Some(value.trim().to_string()),
+            "user-agent" => user_agent = Some(value.trim().to_string()),
+            "content-length" => content_length = value.trim().parse().ok()?,
+            _ => {}
+        }
+    }
+
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body).ok()?;
+
+    Some(Request {
+        method,
+        path,
+        cookie,
+        user_agent,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn write_response(mut stream: TcpStream, response: &Response) {
+    let _ = write!(
+        stream,
+        "HTTP/1.1 {} Mock\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.body.len(),
+        response.body
+    );
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A stand-in for the `aoc` command of aoc-cli. It appends its arguments to `aoc-calls.txt` next to it,
+/// writes the input and puzzle of day 1 to the requested files and accepts every answer.
+/// Exits with `$AOC_CLI_STATUS`, if set.
+pub const FAKE_AOC_CLI: &str = r#"#!/bin/sh
+echo "$@" >> "$(dirname "$0")/aoc-calls.txt"
+while [ $# -gt 0 ]; do
+    case "$1" in
+        --input-file) printf '1\n2\n3\n' > "$2"; shift ;;
+        --puzzle-file) printf '## --- Day 1: Mock ---\n\nCount the lines.\n' > "$2"; shift ;;
+        submit) echo "That's the right answer!  You are one gold star closer to decorating the North Pole." ;;
+    esac
+    shift
+done
+exit "${AOC_CLI_STATUS:-0}"
+"#;
+
+/// A temporary directory to run the template binaries in, removed when dropped.
+pub struct Workspace {
+    pub root: PathBuf,
+}
+
+impl Workspace {
+    pub fn new(name: &str) -> Self {
+        let root = env::temp_dir()
+            .join("aoc-template-tests")
+            .join(format!("{name}-{}", std::process::id()));
+
+        let _ = fs::remove_dir_all(&root);
+        // NOTE: mirrors the layout of the template, which always has a `src/bin` directory.
+        fs::create_dir_all(root.join("src").join("bin")).unwrap();
+
+        Self { root }
+    }
+
+    /// Install `script` as the `aoc` command. Commands of the workspace find it first on their `PATH`.
+    #[cfg(unix)]
+    pub fn install_aoc_cli(&self, script: &str) {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = self.root.join("bin").join("aoc");
+        self.write("bin/aoc", script);
+        fs::set_permissions(path, fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    /// Command for the template command-line, configured to talk to `server`.
+    pub fn cli(&self, server: &MockServer) -> Command {
+        self.command(env!("CARGO_BIN_EXE_advent_of_code"), server)
+    }
+
+    /// Command for a solution of 2025 day 1 that counts the lines of its input.
+    pub fn solution(&self, server: &MockServer) -> Command {
+        self.command(mock_solution(), server)
+    }
+
+    fn command(&self, program: &str, server: &MockServer) -> Command {
+        let mut paths = vec![self.root.join("bin")];
+        paths.extend(env::split_paths(&env::var_os("PATH").unwrap_or_default()));
+
+        let mut command = Command::new(program);
+        command
+            .env("PATH", env::join_paths(paths).unwrap())
+            .current_dir(&self.root)
+            .env("AOC_YEAR", "2025")
+            .env("AOC_BASE_URL", server.url())
+            .env("AOC_SESSION", SESSION)
+            // NOTE: keeps the session files of the machine running the tests out of reach.
+            .env("HOME", &self.root)
+            .env_remove("USERPROFILE")
+            .env_remove("AOC_SESSION_FILE")
+            .env_remove("ADVENT_OF_CODE_SESSION")
+            .env_remove("AOC_BACKEND")
+            .env_remove("AOC_USER_AGENT");
+        command
+    }
+
+    pub fn read(&self, path: &str) -> Option<String> {
+        fs::read_to_string(self.root.join(path)).ok()
+    }
+
+    pub fn write(&self, path: &str, contents: &str) {
+        let path = self.root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+}
+
+impl Drop for Workspace {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+/// Path of the mock solution in `tests/fixtures`, built once per test process.
+/// It is an example of the package, so it is built next to the template binary under test.
+fn mock_solution() -> &'static str {
+    static PATH: OnceLock<String> = OnceLock::new();
+
+    PATH.get_or_init(|| {
+        let profile_dir = Path::new(env!("CARGO_BIN_EXE_advent_of_code"))
+            .parent()
+            .unwrap();
+        let target_dir = profile_dir.parent().unwrap();
+
+        let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+        command
+            .args(["build", "--quiet", "--example", "mock-solution"])
+            .args(["--features", "test_lib"])
+            .env("CARGO_TARGET_DIR", target_dir)
+            .current_dir(env!("CARGO_MANIFEST_DIR"));
+        if profile_dir.ends_with("release") {
+            command.arg("--release");
+        }
+
+        let status = command.status().unwrap();
+        assert!(status.success(), "failed to build the mock solution");
+
+        profile_dir
+            .join("examples")
+            .join(format!("mock-solution{}", env::consts::EXE_SUFFIX))
+            .to_string_lossy()
+            .into_owned()
+    })
+}
+
+/// Stdout and stderr of a finished command.
+pub fn output_text(output: &Output) -> String {
+    format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    )
+}
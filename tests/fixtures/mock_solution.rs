@@ -0,0 +1,12 @@
+//! Solution used by the integration tests to exercise the runner, e.g. submitting answers.
+advent_of_code::solution!(2025, 1);
+
+use advent_of_code::template::{InputError, parse_lines};
+
+pub fn part_one(input: &str) -> Result<u64, InputError> {
+    Ok(parse_lines(input, str::parse::<u64>)?.len() as u64)
+}
+
+pub fn part_two(_input: &str) -> Option<u64> {
+    None
+}